@@ -0,0 +1,131 @@
+use std::fmt;
+
+use crate::{Line, ParseError, cancel::TimedOut};
+
+/// A puzzle answer with its numeric type preserved.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Signed(i64),
+    Unsigned(u64),
+    Text(String),
+    /// The part has no puzzle to solve (e.g. the last day's second star).
+    None,
+    /// The solver gave up when its `Cancel` token fired.
+    TimedOut,
+}
+
+impl Answer {
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            Answer::Signed(_) => "signed",
+            Answer::Unsigned(_) => "unsigned",
+            Answer::Text(_) => "text",
+            Answer::None => "none",
+            Answer::TimedOut => "timed_out",
+        }
+    }
+
+    /// Whether `self` and `other` are the same answer. Integers match by value whatever
+    /// their signedness, since a written-down `6` cannot say which it is, but text only
+    /// ever matches text: a quoted `"3"` is not the integer 3.
+    pub fn matches(&self, other: &Answer) -> bool {
+        match (self, other) {
+            (Answer::Signed(signed), Answer::Unsigned(unsigned))
+            | (Answer::Unsigned(unsigned), Answer::Signed(signed)) => {
+                u64::try_from(*signed) == Ok(*unsigned)
+            }
+            (a, b) => a == b,
+        }
+    }
+
+    /// Parses an answer written as a TOML value: an integer, or a quoted string with
+    /// `\"`, `\\`, `\n` and `\t` escapes. A trailing `# comment` is ignored.
+    pub fn parse(line: &Line, value: &str) -> Result<Answer, ParseError> {
+        if let Some(rest) = value.strip_prefix('"') {
+            let mut text = String::new();
+            let mut chars = rest.chars();
+            while let Some(c) = chars.next() {
+                match c {
+                    '"' => {
+                        let trailing = chars.as_str().trim_start();
+                        if !trailing.is_empty() && !trailing.starts_with('#') {
+                            return Err(line.error(trailing, "end of line"));
+                        }
+                        return Ok(Answer::Text(text));
+                    }
+                    '\\' => match chars.next() {
+                        Some('"') => text.push('"'),
+                        Some('\\') => text.push('\\'),
+                        Some('n') => text.push('\n'),
+                        Some('t') => text.push('\t'),
+                        _ => {
+                            return Err(line.error(
+                                value,
+                                "a string with `\\\"`, `\\\\`, `\\n` or `\\t` escapes",
+                            ));
+                        }
+                    },
+                    c => text.push(c),
+                }
+            }
+            return Err(line.missing("a closing `\"`"));
+        }
+
+        let token = value.split('#').next().unwrap_or_default().trim_end();
+        if token.starts_with('-') {
+            line.parse(token, "an integer or a quoted string")
+                .map(Answer::Signed)
+        } else {
+            line.parse(token, "an integer or a quoted string")
+                .map(Answer::Unsigned)
+        }
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Signed(v) => write!(f, "{}", v),
+            Answer::Unsigned(v) => write!(f, "{}", v),
+            Answer::Text(v) => write!(f, "{}", v),
+            Answer::None => write!(f, "-"),
+            Answer::TimedOut => write!(f, "timed out"),
+        }
+    }
+}
+
+impl From<i64> for Answer {
+    fn from(value: i64) -> Self {
+        Answer::Signed(value)
+    }
+}
+
+impl From<u64> for Answer {
+    fn from(value: u64) -> Self {
+        Answer::Unsigned(value)
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(value: usize) -> Self {
+        Answer::Unsigned(value as u64)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<()> for Answer {
+    fn from(_: ()) -> Self {
+        Answer::None
+    }
+}
+
+impl<T: Into<Answer>> From<Result<T, TimedOut>> for Answer {
+    fn from(value: Result<T, TimedOut>) -> Self {
+        value.map_or(Answer::TimedOut, Into::into)
+    }
+}
@@ -0,0 +1,156 @@
+//! Cooperative cancellation of long-running solvers.
+//!
+//! A solver that may run for a long time takes a `Cancel` token and checks it in its inner
+//! loops, returning `Err(TimedOut)` once the token's deadline passes or someone cancels it.
+//! Runners hand the token to `Solution` implementations through `with`, which makes it the
+//! thread's `Cancel::current()`.
+
+use std::{
+    cell::RefCell,
+    fmt,
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    },
+    time::{Duration, Instant},
+};
+
+/// Returned by solvers that gave up because their `Cancel` token fired.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimedOut;
+
+impl fmt::Display for TimedOut {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "timed out")
+    }
+}
+
+/// A token that fires at its deadline, if it has one, or when any clone of it is cancelled.
+#[derive(Debug, Clone, Default)]
+pub struct Cancel {
+    cancelled: Arc<AtomicBool>,
+    deadline: Option<Instant>,
+}
+
+impl Cancel {
+    /// A token that only fires when cancelled.
+    pub fn new() -> Self {
+        Cancel::default()
+    }
+
+    pub fn with_deadline(deadline: Instant) -> Self {
+        Cancel {
+            cancelled: Arc::default(),
+            deadline: Some(deadline),
+        }
+    }
+
+    /// A token that fires `limit` from now, or only when cancelled if that is too far off
+    /// for an `Instant`.
+    pub fn after(limit: Duration) -> Self {
+        match Instant::now().checked_add(limit) {
+            Some(deadline) => Cancel::with_deadline(deadline),
+            None => Cancel::new(),
+        }
+    }
+
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+            || self
+                .deadline
+                .is_some_and(|deadline| Instant::now() >= deadline)
+    }
+
+    /// `Err(TimedOut)` once the token has fired, for solvers to `?` in their loops.
+    pub fn check(&self) -> Result<(), TimedOut> {
+        if self.is_cancelled() {
+            Err(TimedOut)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// The token installed by `with` on this thread, or one that never fires.
+    pub fn current() -> Self {
+        CURRENT.with_borrow(|current| current.clone().unwrap_or_default())
+    }
+}
+
+/// Parses a `--timeout` value: a positive number of seconds.
+pub fn parse_timeout(value: &str) -> Result<Duration, String> {
+    value
+        .parse()
+        .ok()
+        .and_then(|seconds| Duration::try_from_secs_f64(seconds).ok())
+        .filter(|timeout| !timeout.is_zero())
+        .ok_or_else(|| {
+            format!(
+                "invalid timeout `{}`, expected a positive number of seconds",
+                value
+            )
+        })
+}
+
+thread_local! {
+    static CURRENT: RefCell<Option<Cancel>> = const { RefCell::new(None) };
+}
+
+/// Runs `f` with `cancel` as this thread's `Cancel::current()`.
+pub fn with<T>(cancel: Cancel, f: impl FnOnce() -> T) -> T {
+    /// Restores the previous token even if `f` panics.
+    struct Restore(Option<Cancel>);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            CURRENT.set(self.0.take());
+        }
+    }
+
+    let _restore = Restore(CURRENT.replace(Some(cancel)));
+    f()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cancel() {
+        let cancel = Cancel::new();
+        let clone = cancel.clone();
+        assert_eq!(cancel.check(), Ok(()));
+        clone.cancel();
+        assert_eq!(cancel.check(), Err(TimedOut));
+    }
+
+    #[test]
+    fn test_deadline() {
+        assert!(Cancel::after(Duration::ZERO).is_cancelled());
+        assert!(!Cancel::after(Duration::from_secs(60)).is_cancelled());
+
+        let forever = Cancel::after(parse_timeout("1e19").unwrap());
+        assert!(!forever.is_cancelled());
+        forever.cancel();
+        assert!(forever.is_cancelled());
+    }
+
+    #[test]
+    fn test_parse_timeout() {
+        assert_eq!(parse_timeout("1.5"), Ok(Duration::from_millis(1500)));
+        assert!(parse_timeout("0").is_err());
+        assert!(parse_timeout("-2").is_err());
+        assert!(parse_timeout("soon").is_err());
+    }
+
+    #[test]
+    fn test_current() {
+        assert!(!Cancel::current().is_cancelled());
+        let cancelled = with(Cancel::after(Duration::ZERO), || Cancel::current().check());
+        assert_eq!(cancelled, Err(TimedOut));
+        assert!(!Cancel::current().is_cancelled());
+    }
+}
@@ -0,0 +1,245 @@
+//! Puzzle examples stored as data rather than code.
+//!
+//! Each example is a pair of files in `dayNN/examples/`: `NAME.txt` holds the input exactly
+//! as given, trailing spaces and all, and `NAME.toml` the answers it should produce:
+//!
+//! ```toml
+//! part1 = 3
+//! part2 = "text answers are quoted"
+//! ```
+//!
+//! Parts left out of the sidecar are not run, so an example may cover a single part.
+//! `example_tests!` turns the directory into a test that checks every strategy of every
+//! listed part, which makes adding a regression case a matter of adding two files.
+
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::{Answer, ParseError, Part, Puzzle, StrategyChoice, lines};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Example {
+    pub name: String,
+    pub input: String,
+    pub expected: Vec<(Part, Answer)>,
+}
+
+/// The examples in `dir`, in name order. Every `NAME.txt` needs a `NAME.toml` beside it.
+pub fn load(dir: &Path) -> Result<Vec<Example>, String> {
+    let mut paths: Vec<PathBuf> = fs::read_dir(dir)
+        .and_then(|entries| {
+            entries
+                .map(|entry| entry.map(|entry| entry.path()))
+                .collect()
+        })
+        .map_err(|e| format!("cannot read {}: {}", dir.display(), e))?;
+    paths.retain(|path| path.extension().is_some_and(|ext| ext == "txt"));
+    paths.sort();
+
+    paths
+        .iter()
+        .map(|path| {
+            let read = |path: &Path| {
+                fs::read_to_string(path).map_err(|e| match e.kind() {
+                    io::ErrorKind::NotFound => format!("{} is missing", path.display()),
+                    _ => format!("cannot read {}: {}", path.display(), e),
+                })
+            };
+            let sidecar = path.with_extension("toml");
+            let answers = read(&sidecar)?;
+            Ok(Example {
+                name: path
+                    .file_stem()
+                    .unwrap_or_default()
+                    .to_string_lossy()
+                    .into(),
+                input: read(path)?,
+                expected: parse_expected(&answers)
+                    .map_err(|e| format!("{}: {}", sidecar.display(), e.render(&answers)))?,
+            })
+        })
+        .collect()
+}
+
+/// Parses a sidecar of `partN = answer` lines, with `#` comments and blank lines allowed.
+pub fn parse_expected(text: &str) -> Result<Vec<(Part, Answer)>, ParseError> {
+    let mut expected: Vec<(Part, Answer)> = Vec::new();
+    for line in lines(text) {
+        let content = line.text.trim();
+        if content.is_empty() || content.starts_with('#') {
+            continue;
+        }
+
+        let (key, value) = content
+            .split_once('=')
+            .ok_or_else(|| line.error(content, "`partN = answer`"))?;
+        let part = match key.trim() {
+            "part1" => Part::One,
+            "part2" => Part::Two,
+            key => return Err(line.error(key, "`part1` or `part2`")),
+        };
+        if expected.iter().any(|&(p, _)| p == part) {
+            return Err(line.error(key.trim(), "each part at most once"));
+        }
+        expected.push((part, Answer::parse(&line, value.trim())?));
+    }
+    Ok(expected)
+}
+
+/// Runs every strategy of the parts `example` lists and describes each wrong answer.
+pub fn check(puzzle: &Puzzle, example: &Example) -> Vec<String> {
+    let parts: Vec<Part> = example.expected.iter().map(|&(part, _)| part).collect();
+    let report = match puzzle.run_with(&example.input, &parts, StrategyChoice::All) {
+        Ok(report) => report,
+        Err(e) => return vec![format!("{}: {}", example.name, e.render(&example.input))],
+    };
+
+    let mut failures = Vec::new();
+    for (part, expected) in &example.expected {
+        for report in report.parts.iter().filter(|p| p.part == *part) {
+            if !report.answer.matches(expected) {
+                failures.push(format!(
+                    "{}: part {} ({}) expected {} {}, got {} {}",
+                    example.name,
+                    part.number(),
+                    report.strategy,
+                    expected.type_name(),
+                    expected,
+                    report.answer.type_name(),
+                    report.answer
+                ));
+            }
+        }
+    }
+    failures
+}
+
+/// Checks every example in `dir` against `puzzle`, panicking with all the failures.
+pub fn assert_examples(puzzle: &Puzzle, dir: &Path) {
+    let examples = load(dir).unwrap_or_else(|e| panic!("{}", e));
+    let failures: Vec<String> = examples
+        .iter()
+        .flat_map(|example| check(puzzle, example))
+        .collect();
+    assert!(
+        failures.is_empty(),
+        "examples in {} failed:\n{}",
+        dir.display(),
+        failures.join("\n")
+    );
+}
+
+/// A test named `examples` checking `$solution` against the crate's `examples/` directory.
+#[macro_export]
+macro_rules! example_tests {
+    ($solution:ty) => {
+        #[test]
+        fn examples() {
+            $crate::examples::assert_examples(
+                &$crate::Puzzle::of::<$solution>(),
+                ::std::path::Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/examples")),
+            );
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Solution;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        const DAY: u8 = 0;
+
+        type Input = Vec<u64>;
+        type Answer1 = u64;
+        type Answer2 = String;
+
+        fn parse(input: &str) -> Result<Self::Input, ParseError> {
+            lines(input)
+                .map(|line| line.parse(line.text, "a number"))
+                .collect()
+        }
+
+        fn part1(numbers: &Self::Input) -> Self::Answer1 {
+            numbers.iter().sum()
+        }
+
+        fn part2(numbers: &Self::Input) -> Self::Answer2 {
+            format!("{} numbers", numbers.len())
+        }
+    }
+
+    fn example(input: &str, sidecar: &str) -> Example {
+        Example {
+            name: "example".to_string(),
+            input: input.to_string(),
+            expected: parse_expected(sidecar).unwrap(),
+        }
+    }
+
+    #[test]
+    fn test_parse_expected() {
+        assert_eq!(
+            parse_expected("# from the puzzle\npart2 = \"two\"\n\npart1 = 3 # three\n"),
+            Ok(vec![
+                (Part::Two, Answer::Text("two".to_string())),
+                (Part::One, Answer::Unsigned(3))
+            ])
+        );
+        assert_eq!(
+            parse_expected("part3 = 1").unwrap_err(),
+            ParseError::new(1, 1, "part3", "`part1` or `part2`")
+        );
+        assert!(parse_expected("part1 = 1\npart1 = 2").is_err());
+        assert!(parse_expected("part1 3").is_err());
+    }
+
+    #[test]
+    fn test_check() {
+        let puzzle = Puzzle::of::<Sum>();
+        assert_eq!(
+            check(
+                &puzzle,
+                &example("1\n2\n", "part1 = 3\npart2 = \"2 numbers\"")
+            ),
+            Vec::<String>::new()
+        );
+        assert_eq!(
+            check(&puzzle, &example("1\n2\n", "part1 = 4")),
+            ["example: part 1 (default) expected unsigned 4, got unsigned 3"]
+        );
+        assert_eq!(
+            check(&puzzle, &example("1\n2\n", "part1 = \"3\"")),
+            ["example: part 1 (default) expected text 3, got unsigned 3"]
+        );
+        assert_eq!(check(&puzzle, &example("1\nx\n", "part1 = 1")).len(), 1);
+    }
+
+    #[test]
+    fn test_load() {
+        let dir = std::env::temp_dir().join(format!("aoc-examples-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("b.txt"), "1 \n").unwrap();
+        fs::write(dir.join("b.toml"), "part1 = 1").unwrap();
+        fs::write(dir.join("a.txt"), "2").unwrap();
+
+        assert!(load(&dir).unwrap_err().contains("a.toml is missing"));
+
+        fs::write(dir.join("a.toml"), "").unwrap();
+        let examples = load(&dir).unwrap();
+        assert_eq!(
+            examples
+                .iter()
+                .map(|e| (e.name.as_str(), e.input.as_str()))
+                .collect::<Vec<_>>(),
+            [("a", "2"), ("b", "1 \n")]
+        );
+        assert_eq!(examples[0].expected, []);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,123 @@
+use std::{
+    env, error, fmt,
+    fs::read_to_string,
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
+
+/// Directory containing the `dayNN/input.txt` files, used when no explicit input is given.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// Where a day's puzzle input is read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    /// `dayNN/input.txt`, relative to `$AOC_INPUT_DIR` or the current directory.
+    Default,
+    File(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    /// Interprets a command-line argument, where `-` means stdin.
+    pub fn from_arg(arg: &str) -> Self {
+        if arg == "-" {
+            InputSource::Stdin
+        } else {
+            InputSource::File(PathBuf::from(arg))
+        }
+    }
+
+    pub fn read(&self, day: u8) -> Result<String, InputError> {
+        let result = match self {
+            InputSource::Default => read_to_string(default_input_path(day)),
+            InputSource::File(path) => read_to_string(path),
+            InputSource::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input).map(|_| input)
+            }
+        };
+
+        result.map_err(|error| InputError {
+            source: self.describe(day),
+            error,
+        })
+    }
+
+    fn describe(&self, day: u8) -> String {
+        match self {
+            InputSource::Default => default_input_path(day).display().to_string(),
+            InputSource::File(path) => path.display().to_string(),
+            InputSource::Stdin => "stdin".to_string(),
+        }
+    }
+}
+
+pub fn input_path(day: u8) -> PathBuf {
+    PathBuf::from(format!("day{:02}", day)).join("input.txt")
+}
+
+pub fn default_input_path(day: u8) -> PathBuf {
+    let dir = env::var_os(INPUT_DIR_VAR).map(PathBuf::from);
+    input_path_in(dir.as_deref(), day)
+}
+
+fn input_path_in(dir: Option<&Path>, day: u8) -> PathBuf {
+    match dir {
+        Some(dir) => dir.join(input_path(day)),
+        None => input_path(day),
+    }
+}
+
+#[derive(Debug)]
+pub struct InputError {
+    source: String,
+    error: io::Error,
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "cannot read {}: {}", self.source, self.error)
+    }
+}
+
+impl error::Error for InputError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_arg() {
+        assert_eq!(InputSource::from_arg("-"), InputSource::Stdin);
+        assert_eq!(
+            InputSource::from_arg("inputs/day01.txt"),
+            InputSource::File(PathBuf::from("inputs/day01.txt"))
+        );
+    }
+
+    #[test]
+    fn test_input_path_in() {
+        assert_eq!(input_path_in(None, 3), PathBuf::from("day03/input.txt"));
+        assert_eq!(
+            input_path_in(Some(Path::new("/srv/aoc")), 12),
+            PathBuf::from("/srv/aoc/day12/input.txt")
+        );
+    }
+
+    #[test]
+    fn test_read_file() {
+        let path = env::temp_dir().join(format!("aoc-core-input-{}.txt", std::process::id()));
+        std::fs::write(&path, "L68\nR48\n").unwrap();
+
+        let source = InputSource::File(path.clone());
+        assert_eq!(source.read(1).unwrap(), "L68\nR48\n");
+
+        std::fs::remove_file(&path).unwrap();
+        let error = source.read(1).unwrap_err();
+        assert!(error.to_string().starts_with("cannot read "));
+    }
+}
@@ -0,0 +1,398 @@
+use std::{env, process::ExitCode, slice, time::Duration};
+
+mod answer;
+pub mod cancel;
+pub mod examples;
+mod grid;
+mod input;
+mod json;
+pub mod output;
+mod parse;
+mod puzzle;
+pub mod render;
+mod strategy;
+pub mod trace;
+
+pub use answer::Answer;
+pub use cancel::{Cancel, TimedOut};
+pub use grid::{Grid, Pos};
+pub use input::{INPUT_DIR_VAR, InputError, InputSource, default_input_path, input_path};
+pub use json::Json;
+pub use output::Format;
+pub use parse::{Line, ParseError, lines};
+pub use puzzle::{PartReport, Puzzle, Report};
+pub use render::{Animation, Frame};
+pub use strategy::{DEFAULT_STRATEGY, Strategy, StrategyChoice};
+pub use trace::{Event, Verbosity};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+/// A single day's puzzle: parse the input once, then solve each part from it.
+pub trait Solution {
+    const DAY: u8;
+
+    type Input;
+    type Answer1: Into<Answer>;
+    type Answer2: Into<Answer>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+    fn part1(input: &Self::Input) -> Self::Answer1;
+    fn part2(input: &Self::Input) -> Self::Answer2;
+
+    /// Named alternative implementations of the parts, the default of each part first.
+    /// Parts with none registered have a single `default` strategy calling `part1`/`part2`.
+    fn strategies() -> Vec<Strategy<Self::Input>> {
+        Vec::new()
+    }
+
+    /// Frames showing how the solver works through the input, for days that can draw it.
+    fn animate(_input: &Self::Input) -> Option<Animation> {
+        None
+    }
+}
+
+pub fn solve<S: Solution>(input: &S::Input, part: Part) -> Answer {
+    match part {
+        Part::One => S::part1(input).into(),
+        Part::Two => S::part2(input).into(),
+    }
+}
+
+/// Usage of the day binaries, which all share `Args`.
+pub const USAGE: &str = "Usage: dayNN [<input path>|-] [--format <text|json|csv>]
+             [--strategy <name> | --cross-check]
+             [--explain <summary|steps|details>] [--timeout <seconds>]";
+
+/// Command-line arguments of a day binary.
+#[derive(Debug, PartialEq)]
+pub struct Args {
+    pub input: InputSource,
+    pub format: Format,
+    pub strategy: Option<String>,
+    /// Run every strategy of each part and fail if their answers differ.
+    pub cross_check: bool,
+    /// Trace each part's intermediate steps up to this verbosity.
+    pub explain: Option<Verbosity>,
+    /// Time after which solvers that support cancellation give up.
+    pub timeout: Option<Duration>,
+}
+
+impl Args {
+    pub fn parse<I: IntoIterator<Item = String>>(args: I) -> Result<Self, String> {
+        let mut input = None;
+        let mut format = Format::Text;
+        let mut strategy = None;
+        let mut cross_check = false;
+        let mut explain = None;
+        let mut timeout = None;
+
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--format" | "-f" => {
+                    let value = args.next().ok_or("missing value for --format")?;
+                    format = value.parse()?;
+                }
+                "--strategy" | "-s" => {
+                    strategy = Some(args.next().ok_or("missing value for --strategy")?);
+                }
+                "--cross-check" => cross_check = true,
+                "--explain" | "-e" => {
+                    let value = args.next().ok_or("missing value for --explain")?;
+                    explain = Some(value.parse()?);
+                }
+                "--timeout" => {
+                    let value = args.next().ok_or("missing value for --timeout")?;
+                    timeout = Some(cancel::parse_timeout(&value)?);
+                }
+                _ if arg.starts_with('-') && arg != "-" => {
+                    return Err(format!("unknown option `{}`", arg));
+                }
+                _ if input.is_some() => return Err(format!("unexpected argument `{}`", arg)),
+                _ => input = Some(InputSource::from_arg(&arg)),
+            }
+        }
+
+        if strategy.is_some() && cross_check {
+            return Err("--strategy and --cross-check cannot be combined".to_string());
+        }
+
+        Ok(Args {
+            input: input.unwrap_or(InputSource::Default),
+            format,
+            strategy,
+            cross_check,
+            explain,
+            timeout,
+        })
+    }
+
+    pub fn choice(&self) -> StrategyChoice<'_> {
+        StrategyChoice::from_flags(self.strategy.as_deref(), self.cross_check)
+    }
+
+    pub fn from_env() -> Result<Self, String> {
+        Args::parse(env::args().skip(1))
+    }
+}
+
+/// Prints a day's answers in `format`; text output skips parts without an answer, names
+/// the strategy of parts solved by several, and lists each part's trace below it.
+pub fn print_report(report: &Report, format: Format) {
+    match format {
+        Format::Text => {
+            for part in &report.parts {
+                if part.answer == Answer::None {
+                    continue;
+                }
+                if report.is_cross_checked(part.part) {
+                    println!(
+                        "Part {} ({}): {}",
+                        part.part.number(),
+                        part.strategy,
+                        part.answer
+                    );
+                } else {
+                    println!("Part {}: {}", part.part.number(), part.answer);
+                }
+                for event in &part.trace {
+                    println!("  {}", event);
+                }
+            }
+        }
+        Format::Json => println!("{}", output::to_json(slice::from_ref(report))),
+        Format::Csv => print!("{}", output::to_csv(slice::from_ref(report))),
+    }
+}
+
+/// Entry point of a day binary: reads the input named on the command line and prints both
+/// answers.
+pub fn run<S: Solution>() -> ExitCode {
+    let args = match Args::from_env() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Error: {}\n\n{}", e, USAGE);
+            return ExitCode::from(2);
+        }
+    };
+
+    let input = match args.input.read(S::DAY) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("Error reading input: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let puzzle = Puzzle::of::<S>();
+    if let Some(name) = &args.strategy
+        && let Err(e) = puzzle.check_strategy(name, &Part::ALL)
+    {
+        eprintln!("Error: {}", e);
+        return ExitCode::from(2);
+    }
+
+    let run = || puzzle.run_with(&input, &Part::ALL, args.choice());
+    let run = || match args.explain {
+        Some(verbosity) => trace::explain(verbosity, run),
+        None => run(),
+    };
+    let result = match args.timeout {
+        Some(timeout) => cancel::with(Cancel::after(timeout), run),
+        None => run(),
+    };
+    match result {
+        Ok(report) => {
+            print_report(&report, args.format);
+            let disagreements = report.disagreements();
+            for &part in &disagreements {
+                eprintln!("Error: {}", describe_disagreement(&report, part));
+            }
+            let timed_out = report.parts.iter().any(|p| p.answer == Answer::TimedOut);
+            if disagreements.is_empty() && !timed_out {
+                ExitCode::SUCCESS
+            } else {
+                ExitCode::FAILURE
+            }
+        }
+        Err(e) => {
+            eprintln!("Error parsing input: {}", e.render(&input));
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// A message listing each strategy's answer for a part whose strategies disagree.
+pub fn describe_disagreement(report: &Report, part: Part) -> String {
+    let answers: Vec<String> = report
+        .parts
+        .iter()
+        .filter(|p| p.part == part)
+        .map(|p| format!("{} = {}", p.strategy, p.answer))
+        .collect();
+    format!(
+        "day {} part {} strategies disagree: {}",
+        report.day,
+        part.number(),
+        answers.join(", ")
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Example;
+
+    impl Solution for Example {
+        const DAY: u8 = 0;
+
+        type Input = Vec<i64>;
+        type Answer1 = i64;
+        type Answer2 = ();
+
+        fn parse(input: &str) -> Result<Self::Input, ParseError> {
+            lines(input)
+                .map(|line| line.parse(line.text, "an integer"))
+                .collect()
+        }
+
+        fn part1(input: &Self::Input) -> Self::Answer1 {
+            input.iter().sum()
+        }
+
+        fn part2(_input: &Self::Input) -> Self::Answer2 {}
+
+        fn strategies() -> Vec<Strategy<Self::Input>> {
+            vec![
+                Strategy::new(Part::One, "sum", |input| Self::part1(input).into()),
+                Strategy::new(Part::One, "max", |input| {
+                    input.iter().copied().max().unwrap_or(0).into()
+                }),
+            ]
+        }
+    }
+
+    #[test]
+    fn test_solve() {
+        let input = Example::parse("1\n-5\n2").unwrap();
+        assert_eq!(solve::<Example>(&input, Part::One), Answer::Signed(-2));
+        assert_eq!(solve::<Example>(&input, Part::Two), Answer::None);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            Example::parse("1\nx").unwrap_err(),
+            ParseError::new(2, 1, "x", "an integer")
+        );
+        assert!(Puzzle::of::<Example>().run("1\nx", &Part::ALL).is_err());
+    }
+
+    #[test]
+    fn test_args() {
+        let args = |line: &str| Args::parse(line.split_whitespace().map(str::to_string));
+        assert_eq!(
+            args("input.txt --format json"),
+            Ok(Args {
+                input: InputSource::File("input.txt".into()),
+                format: Format::Json,
+                strategy: None,
+                cross_check: false,
+                explain: None,
+                timeout: None,
+            })
+        );
+        assert_eq!(
+            args("-"),
+            Ok(Args {
+                input: InputSource::Stdin,
+                format: Format::Text,
+                strategy: None,
+                cross_check: false,
+                explain: None,
+                timeout: None,
+            })
+        );
+        assert_eq!(
+            args("--strategy fast").unwrap().choice(),
+            StrategyChoice::Named("fast")
+        );
+        assert_eq!(args("--cross-check").unwrap().choice(), StrategyChoice::All);
+        assert!(args("--strategy fast --cross-check").is_err());
+        assert_eq!(
+            args("--explain details").unwrap().explain,
+            Some(Verbosity::Details)
+        );
+        assert!(args("--explain").is_err());
+        assert_eq!(
+            args("--timeout 2").unwrap().timeout,
+            Some(Duration::from_secs(2))
+        );
+        assert!(args("--timeout 0").is_err());
+        assert!(args("--format yaml").is_err());
+        assert!(args("a.txt b.txt").is_err());
+        assert!(args("--verbose").is_err());
+    }
+
+    #[test]
+    fn test_puzzle_run() {
+        let report = Puzzle::of::<Example>().run("4\n5", &[Part::One]).unwrap();
+        assert_eq!(report.day, 0);
+        assert_eq!(report.parts.len(), 1);
+        assert_eq!(report.parts[0].part, Part::One);
+        assert_eq!(report.parts[0].answer, Answer::Signed(9));
+    }
+
+    #[test]
+    fn test_strategies() {
+        let puzzle = Puzzle::of::<Example>();
+        assert_eq!(puzzle.strategy_names(Part::One), ["sum", "max"]);
+        assert_eq!(puzzle.strategy_names(Part::Two), [DEFAULT_STRATEGY]);
+        assert!(puzzle.check_strategy("max", &Part::ALL).is_ok());
+        assert!(puzzle.check_strategy("max", &[Part::Two]).is_err());
+
+        let report = puzzle
+            .run_with("4\n5", &Part::ALL, StrategyChoice::Named("max"))
+            .unwrap();
+        let solved: Vec<_> = report.parts.iter().map(|p| p.strategy).collect();
+        assert_eq!(solved, ["max", DEFAULT_STRATEGY]);
+        assert_eq!(report.parts[0].answer, Answer::Signed(5));
+    }
+
+    #[test]
+    fn test_cross_check() {
+        let puzzle = Puzzle::of::<Example>();
+
+        let agreeing = puzzle
+            .run_with("7", &Part::ALL, StrategyChoice::All)
+            .unwrap();
+        assert_eq!(agreeing.parts.len(), 3);
+        assert!(agreeing.is_cross_checked(Part::One));
+        assert!(agreeing.disagreements().is_empty());
+
+        let report = puzzle
+            .run_with("4\n5", &Part::ALL, StrategyChoice::All)
+            .unwrap();
+        assert_eq!(report.disagreements(), [Part::One]);
+        assert_eq!(
+            describe_disagreement(&report, Part::One),
+            "day 0 part 1 strategies disagree: sum = 9, max = 5"
+        );
+    }
+}
@@ -0,0 +1,159 @@
+use std::str::FromStr;
+
+use crate::{Answer, Event, Json, Report};
+
+/// How results are printed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// Human-readable output: `Part N: answer` lines, or the runner's table.
+    Text,
+    Json,
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => Err(format!(
+                "invalid format `{}`, expected text, json or csv",
+                s
+            )),
+        }
+    }
+}
+
+pub const CSV_HEADER: &str = "day,part,strategy,answer,answer_type,parse_time_ns,solve_time_ns";
+
+/// One record per solved part, with integer answers as JSON numbers and, for explained
+/// parts, their trace.
+pub fn to_json(reports: &[Report]) -> Json {
+    let records = reports
+        .iter()
+        .flat_map(|report| {
+            report.parts.iter().map(move |part| {
+                let answer = match &part.answer {
+                    Answer::Signed(v) => Json::from(*v),
+                    Answer::Unsigned(v) => Json::from(*v),
+                    Answer::Text(v) => Json::from(v.as_str()),
+                    Answer::None | Answer::TimedOut => Json::Null,
+                };
+                let mut record = Json::object([
+                    ("day", Json::from(report.day as u64)),
+                    ("part", Json::from(part.part.number() as u64)),
+                    ("strategy", Json::from(part.strategy)),
+                    ("answer", answer),
+                    ("answer_type", Json::from(part.answer.type_name())),
+                    (
+                        "parse_time_ns",
+                        Json::Int(report.parse_time.as_nanos() as i128),
+                    ),
+                    ("solve_time_ns", Json::Int(part.time.as_nanos() as i128)),
+                ]);
+                if let Json::Object(fields) = &mut record
+                    && !part.trace.is_empty()
+                {
+                    let trace = part.trace.iter().map(Event::to_json).collect();
+                    fields.push(("trace".to_string(), Json::Array(trace)));
+                }
+                record
+            })
+        })
+        .collect();
+
+    Json::Array(records)
+}
+
+/// A header line followed by one line per solved part.
+pub fn to_csv(reports: &[Report]) -> String {
+    let mut out = format!("{}\n", CSV_HEADER);
+    for report in reports {
+        for part in &report.parts {
+            let answer = match &part.answer {
+                Answer::None | Answer::TimedOut => String::new(),
+                answer => csv_field(&answer.to_string()),
+            };
+            out.push_str(&format!(
+                "{},{},{},{},{},{},{}\n",
+                report.day,
+                part.part.number(),
+                part.strategy,
+                answer,
+                part.answer.type_name(),
+                report.parse_time.as_nanos(),
+                part.time.as_nanos()
+            ));
+        }
+    }
+    out
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+    use crate::{Part, PartReport};
+
+    fn report() -> Report {
+        Report {
+            day: 6,
+            parse_time: Duration::from_nanos(1500),
+            parts: vec![
+                PartReport {
+                    part: Part::One,
+                    strategy: "default",
+                    answer: Answer::Signed(-4),
+                    time: Duration::from_nanos(20),
+                    trace: Vec::new(),
+                },
+                PartReport {
+                    part: Part::Two,
+                    strategy: "default",
+                    answer: Answer::Text("a, \"b\"".to_string()),
+                    time: Duration::from_nanos(30),
+                    trace: Vec::new(),
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn test_format_from_str() {
+        assert_eq!("csv".parse(), Ok(Format::Csv));
+        assert!("yaml".parse::<Format>().is_err());
+    }
+
+    #[test]
+    fn test_to_json() {
+        assert_eq!(
+            to_json(&[report()]).to_string(),
+            concat!(
+                r#"[{"day":6,"part":1,"strategy":"default","answer":-4,"answer_type":"signed","parse_time_ns":1500,"solve_time_ns":20},"#,
+                r#"{"day":6,"part":2,"strategy":"default","answer":"a, \"b\"","answer_type":"text","parse_time_ns":1500,"solve_time_ns":30}]"#
+            )
+        );
+    }
+
+    #[test]
+    fn test_to_csv() {
+        assert_eq!(
+            to_csv(&[report()]),
+            "day,part,strategy,answer,answer_type,parse_time_ns,solve_time_ns\n\
+             6,1,default,-4,signed,1500,20\n\
+             6,2,default,\"a, \"\"b\"\"\",text,1500,30\n"
+        );
+    }
+}
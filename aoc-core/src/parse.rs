@@ -0,0 +1,161 @@
+use std::{error, fmt, str::FromStr};
+
+/// A malformed puzzle input, pointing at the offending token.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// 1-based line number.
+    pub line: usize,
+    /// 1-based column of the first character of `token`.
+    pub column: usize,
+    pub token: String,
+    pub expected: String,
+}
+
+impl ParseError {
+    pub fn new(
+        line: usize,
+        column: usize,
+        token: impl Into<String>,
+        expected: impl Into<String>,
+    ) -> Self {
+        ParseError {
+            line,
+            column,
+            token: token.into(),
+            expected: expected.into(),
+        }
+    }
+
+    /// Formats the error together with the offending input line and a marker under the token.
+    pub fn render(&self, input: &str) -> String {
+        let mut out = self.to_string();
+
+        if let Some(text) = input.lines().nth(self.line.saturating_sub(1)) {
+            let gutter = self.line.to_string().len();
+            let marker = "^".repeat(self.token.chars().count().max(1));
+            out.push_str(&format!(
+                "\n{:gutter$} |\n{} | {}\n{:gutter$} | {:indent$}{}",
+                "",
+                self.line,
+                text,
+                "",
+                "",
+                marker,
+                gutter = gutter,
+                indent = self.column.saturating_sub(1),
+            ));
+        }
+
+        out
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: expected {}, found ",
+            self.line, self.column, self.expected
+        )?;
+        if self.token.is_empty() {
+            write!(f, "end of line")
+        } else {
+            write!(f, "`{}`", self.token)
+        }
+    }
+}
+
+impl error::Error for ParseError {}
+
+/// One line of puzzle input, used to build errors that point into it.
+#[derive(Debug, Clone, Copy)]
+pub struct Line<'a> {
+    /// 1-based line number.
+    pub number: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    /// Column of `token`, which must be a subslice of this line; anything else maps to the
+    /// end of the line.
+    pub fn column(&self, token: &str) -> usize {
+        let start = self.text.as_ptr() as usize;
+        let offset = (token.as_ptr() as usize)
+            .checked_sub(start)
+            .filter(|&offset| offset <= self.text.len() && self.text.is_char_boundary(offset))
+            .unwrap_or(self.text.len());
+        self.text[..offset].chars().count() + 1
+    }
+
+    pub fn error(&self, token: &str, expected: impl Into<String>) -> ParseError {
+        ParseError::new(self.number, self.column(token), token, expected)
+    }
+
+    /// Error for a token that is missing at the end of the line.
+    pub fn missing(&self, expected: impl Into<String>) -> ParseError {
+        self.error(&self.text[self.text.len()..], expected)
+    }
+
+    pub fn parse<T: FromStr>(
+        &self,
+        token: &str,
+        expected: impl Into<String>,
+    ) -> Result<T, ParseError> {
+        token.parse().map_err(|_| self.error(token, expected))
+    }
+}
+
+pub fn lines(input: &str) -> impl Iterator<Item = Line<'_>> {
+    input.lines().enumerate().map(|(i, text)| Line {
+        number: i + 1,
+        text,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_column() {
+        let line = Line {
+            number: 2,
+            text: "12x5: 1 0 x",
+        };
+        let token = line.text.split_whitespace().last().unwrap();
+        assert_eq!(line.column(token), 11);
+        assert_eq!(line.column("elsewhere"), 12);
+        assert_eq!(line.missing("a count").column, 12);
+    }
+
+    #[test]
+    fn test_parse() {
+        let line = lines("L68\nR4x").nth(1).unwrap();
+        assert_eq!(
+            line.parse::<i64>(&line.text[1..], "a distance"),
+            Err(ParseError::new(2, 2, "4x", "a distance"))
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        let error = ParseError::new(3, 1, "X12", "a direction `L` or `R`");
+        assert_eq!(
+            error.to_string(),
+            "line 3, column 1: expected a direction `L` or `R`, found `X12`"
+        );
+        assert_eq!(
+            ParseError::new(1, 4, "", "a number").to_string(),
+            "line 1, column 4: expected a number, found end of line"
+        );
+    }
+
+    #[test]
+    fn test_render() {
+        let error = ParseError::new(2, 3, "4x", "a distance");
+        assert_eq!(
+            error.render("L68\nR 4x"),
+            "line 2, column 3: expected a distance, found `4x`\n  |\n2 | R 4x\n  |   ^^"
+        );
+    }
+}
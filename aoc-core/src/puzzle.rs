@@ -0,0 +1,154 @@
+use std::time::{Duration, Instant};
+
+use crate::{
+    Animation, Answer, ParseError, Part, Solution,
+    strategy::{self, StrategyChoice},
+    trace::{self, Event},
+};
+
+/// A type-erased handle to a day's `Solution`, so days can be driven uniformly.
+#[derive(Clone, Copy)]
+pub struct Puzzle {
+    pub day: u8,
+    run: fn(&str, &[Part], StrategyChoice) -> Result<Report, ParseError>,
+    parse: fn(&str) -> Result<(), ParseError>,
+    strategy_names: fn(Part) -> Vec<&'static str>,
+    animate: fn(&str) -> Result<Option<Animation>, ParseError>,
+}
+
+impl Puzzle {
+    pub const fn of<S: Solution>() -> Self {
+        Puzzle {
+            day: S::DAY,
+            run: run_timed::<S>,
+            parse: |input| S::parse(input).map(drop),
+            strategy_names: strategy_names::<S>,
+            animate: |input| S::parse(input).map(|input| S::animate(&input)),
+        }
+    }
+
+    /// Runs the default strategy of each part.
+    pub fn run(&self, input: &str, parts: &[Part]) -> Result<Report, ParseError> {
+        self.run_with(input, parts, StrategyChoice::Default)
+    }
+
+    pub fn run_with(
+        &self,
+        input: &str,
+        parts: &[Part],
+        choice: StrategyChoice,
+    ) -> Result<Report, ParseError> {
+        (self.run)(input, parts, choice)
+    }
+
+    /// Parses `input` without solving it, for checking the parser on its own.
+    pub fn parse(&self, input: &str) -> Result<(), ParseError> {
+        (self.parse)(input)
+    }
+
+    /// The day's animation of `input`, or `None` if the day cannot draw itself.
+    pub fn animate(&self, input: &str) -> Result<Option<Animation>, ParseError> {
+        (self.animate)(input)
+    }
+
+    /// Names of the strategies of `part`, the default first.
+    pub fn strategy_names(&self, part: Part) -> Vec<&'static str> {
+        (self.strategy_names)(part)
+    }
+
+    /// Checks that `name` is a strategy of at least one of `parts`.
+    pub fn check_strategy(&self, name: &str, parts: &[Part]) -> Result<(), String> {
+        let mut names = Vec::new();
+        for name in parts.iter().flat_map(|&part| self.strategy_names(part)) {
+            if !names.contains(&name) {
+                names.push(name);
+            }
+        }
+        if names.contains(&name) {
+            Ok(())
+        } else {
+            Err(format!(
+                "day {} has no strategy `{}` (available: {})",
+                self.day,
+                name,
+                names.join(", ")
+            ))
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Report {
+    pub day: u8,
+    pub parse_time: Duration,
+    pub parts: Vec<PartReport>,
+}
+
+impl Report {
+    /// Parts whose strategies returned different answers.
+    pub fn disagreements(&self) -> Vec<Part> {
+        Part::ALL
+            .into_iter()
+            .filter(|&part| {
+                let mut answers = self.parts.iter().filter(|p| p.part == part);
+                answers
+                    .next()
+                    .is_some_and(|first| answers.any(|p| p.answer != first.answer))
+            })
+            .collect()
+    }
+
+    /// Whether `part` was solved by more than one strategy.
+    pub fn is_cross_checked(&self, part: Part) -> bool {
+        self.parts.iter().filter(|p| p.part == part).count() > 1
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PartReport {
+    pub part: Part,
+    pub strategy: &'static str,
+    pub answer: Answer,
+    pub time: Duration,
+    /// Events the part emitted, collected only while explaining.
+    pub trace: Vec<Event>,
+}
+
+fn strategy_names<S: Solution>(part: Part) -> Vec<&'static str> {
+    strategy::strategies::<S>(part)
+        .iter()
+        .map(|strategy| strategy.name)
+        .collect()
+}
+
+fn run_timed<S: Solution>(
+    input: &str,
+    parts: &[Part],
+    choice: StrategyChoice,
+) -> Result<Report, ParseError> {
+    let start = Instant::now();
+    let parsed = S::parse(input)?;
+    let parse_time = start.elapsed();
+
+    let parts = parts
+        .iter()
+        .flat_map(|&part| strategy::choose::<S>(part, choice))
+        .map(|strategy| {
+            let start = Instant::now();
+            let (answer, trace) = trace::capture(|| (strategy.solve)(&parsed));
+            PartReport {
+                part: strategy.part,
+                strategy: strategy.name,
+                answer,
+                time: start.elapsed(),
+                trace,
+            }
+        })
+        .collect();
+
+    Ok(Report {
+        day: S::DAY,
+        parse_time,
+        parts,
+    })
+}
@@ -0,0 +1,212 @@
+//! Pictures of grid states: coloured text for the terminal, PPM images, and animations made
+//! of a frame per solver step.
+
+use std::{
+    fmt, fs,
+    io::{self, Write},
+    path::{Path, PathBuf},
+    thread,
+    time::Duration,
+};
+
+use crate::{Grid, Pos};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    pub const BLACK: Rgb = Rgb(0, 0, 0);
+    pub const WHITE: Rgb = Rgb(255, 255, 255);
+}
+
+/// How one cell is drawn: a character in the terminal, a colour everywhere.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Pixel {
+    pub glyph: char,
+    pub colour: Rgb,
+}
+
+impl Pixel {
+    pub const fn new(glyph: char, colour: Rgb) -> Self {
+        Pixel { glyph, colour }
+    }
+}
+
+/// Cells that know how to draw themselves, for `Frame::of`.
+pub trait Paint {
+    fn pixel(&self) -> Pixel;
+}
+
+/// One picture of a grid.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    pub pixels: Grid<Pixel>,
+}
+
+impl Frame {
+    /// Draws each cell of `grid` with `pixel`, which can highlight cells by position.
+    pub fn new<T>(grid: &Grid<T>, mut pixel: impl FnMut(Pos, &T) -> Pixel) -> Self {
+        let mut pixels = Grid::new(grid.width(), grid.height(), Pixel::new(' ', Rgb::BLACK));
+        for (pos, cell) in grid.iter() {
+            pixels[pos] = pixel(pos, cell);
+        }
+        Frame { pixels }
+    }
+
+    pub fn of<T: Paint>(grid: &Grid<T>) -> Self {
+        Frame::new(grid, |_, cell| cell.pixel())
+    }
+
+    /// The frame as a binary PPM (P6) image, each cell a `scale`-pixel square.
+    pub fn to_ppm(&self, scale: usize) -> Vec<u8> {
+        let (width, height) = (self.pixels.width() * scale, self.pixels.height() * scale);
+        let mut out = format!("P6\n{} {}\n255\n", width, height).into_bytes();
+        for row in self.pixels.rows() {
+            let mut line = Vec::with_capacity(width * 3);
+            for pixel in row {
+                let Rgb(r, g, b) = pixel.colour;
+                for _ in 0..scale {
+                    line.extend_from_slice(&[r, g, b]);
+                }
+            }
+            for _ in 0..scale {
+                out.extend_from_slice(&line);
+            }
+        }
+        out
+    }
+}
+
+/// Each cell's glyph in its colour, using 24-bit ANSI escapes, with the colour reset at the
+/// end of every row.
+impl fmt::Display for Frame {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, row) in self.pixels.rows().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            let mut current = None;
+            for pixel in row {
+                if current != Some(pixel.colour) {
+                    let Rgb(r, g, b) = pixel.colour;
+                    write!(f, "\x1b[38;2;{};{};{}m", r, g, b)?;
+                    current = Some(pixel.colour);
+                }
+                write!(f, "{}", pixel.glyph)?;
+            }
+            write!(f, "\x1b[0m")?;
+        }
+        Ok(())
+    }
+}
+
+/// Frames of a solver's progress, in order.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Animation {
+    pub frames: Vec<Frame>,
+}
+
+impl Animation {
+    pub fn push(&mut self, frame: Frame) {
+        self.frames.push(frame);
+    }
+
+    /// Draws each frame over the previous one, waiting `delay` in between.
+    pub fn play(&self, out: &mut impl Write, delay: Duration) -> io::Result<()> {
+        for (i, frame) in self.frames.iter().enumerate() {
+            if i > 0 {
+                thread::sleep(delay);
+            }
+            writeln!(out, "\x1b[H\x1b[2J{}", frame)?;
+            out.flush()?;
+        }
+        Ok(())
+    }
+
+    /// Writes the frames to `dir` as `frame-0000.ppm`, `frame-0001.ppm`, ... and returns
+    /// their paths.
+    pub fn write_ppm(&self, dir: &Path, scale: usize) -> io::Result<Vec<PathBuf>> {
+        fs::create_dir_all(dir)?;
+        self.frames
+            .iter()
+            .enumerate()
+            .map(|(i, frame)| {
+                let path = dir.join(format!("frame-{:04}.ppm", i));
+                fs::write(&path, frame.to_ppm(scale))?;
+                Ok(path)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    impl Paint for bool {
+        fn pixel(&self) -> Pixel {
+            match self {
+                true => Pixel::new('#', Rgb::WHITE),
+                false => Pixel::new('.', Rgb::BLACK),
+            }
+        }
+    }
+
+    fn frame() -> Frame {
+        let grid = Grid::parse("#.\n##", "`#` or `.`", |ch| Some(ch == '#')).unwrap();
+        Frame::of(&grid)
+    }
+
+    #[test]
+    fn test_ansi() {
+        assert_eq!(
+            frame().to_string(),
+            "\x1b[38;2;255;255;255m#\x1b[38;2;0;0;0m.\x1b[0m\n\
+             \x1b[38;2;255;255;255m##\x1b[0m"
+        );
+    }
+
+    #[test]
+    fn test_ppm() {
+        let ppm = frame().to_ppm(2);
+        let header = b"P6\n4 4\n255\n";
+        assert_eq!(&ppm[..header.len()], header);
+
+        let pixels = &ppm[header.len()..];
+        assert_eq!(pixels.len(), 4 * 4 * 3);
+        // The first row of cells, repeated for both pixel rows: white, white, black, black.
+        let row = [[255; 6], [0; 6]].concat();
+        assert_eq!(&pixels[..12], row.as_slice());
+        assert_eq!(&pixels[12..24], row.as_slice());
+        assert_eq!(&pixels[24..], [255; 24].as_slice());
+    }
+
+    #[test]
+    fn test_new_highlights_by_position() {
+        let grid = Grid::new(2, 1, false);
+        let red = Pixel::new('x', Rgb(255, 0, 0));
+        let frame = Frame::new(&grid, |pos, cell| {
+            if pos == Pos::new(0, 1) {
+                red
+            } else {
+                cell.pixel()
+            }
+        });
+        assert_eq!(frame.pixels.row(0), [false.pixel(), red]);
+    }
+
+    #[test]
+    fn test_write_ppm() {
+        let dir = std::env::temp_dir().join(format!("aoc-render-{}", std::process::id()));
+        let animation = Animation {
+            frames: vec![frame(), frame()],
+        };
+        let paths = animation.write_ppm(&dir, 1).unwrap();
+        assert_eq!(
+            paths,
+            [dir.join("frame-0000.ppm"), dir.join("frame-0001.ppm")]
+        );
+        assert_eq!(fs::read(&paths[1]).unwrap(), frame().to_ppm(1));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
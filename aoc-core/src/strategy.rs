@@ -0,0 +1,72 @@
+use crate::{Answer, Part, Solution};
+
+/// Name of the only strategy of a part that registers no alternatives.
+pub const DEFAULT_STRATEGY: &str = "default";
+
+/// A named implementation of one part.
+pub struct Strategy<I> {
+    pub part: Part,
+    pub name: &'static str,
+    pub solve: fn(&I) -> Answer,
+}
+
+impl<I> Strategy<I> {
+    pub fn new(part: Part, name: &'static str, solve: fn(&I) -> Answer) -> Self {
+        Strategy { part, name, solve }
+    }
+}
+
+/// Which strategies to run for each part.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StrategyChoice<'a> {
+    /// The first registered strategy, which is what `part1`/`part2` compute.
+    Default,
+    /// The named strategy for parts that have one, and the default for the others.
+    Named(&'a str),
+    /// Every strategy, to cross-check them against each other.
+    All,
+}
+
+impl<'a> StrategyChoice<'a> {
+    /// The choice made by a `--strategy <name>` or `--cross-check` command-line flag.
+    pub fn from_flags(strategy: Option<&'a str>, cross_check: bool) -> Self {
+        match strategy {
+            Some(name) => StrategyChoice::Named(name),
+            None if cross_check => StrategyChoice::All,
+            None => StrategyChoice::Default,
+        }
+    }
+}
+
+/// The strategies of `part`: those `S` registers, or its plain `part1`/`part2` as `default`.
+pub fn strategies<S: Solution>(part: Part) -> Vec<Strategy<S::Input>> {
+    let registered: Vec<_> = S::strategies()
+        .into_iter()
+        .filter(|strategy| strategy.part == part)
+        .collect();
+    if !registered.is_empty() {
+        return registered;
+    }
+
+    let solve: fn(&S::Input) -> Answer = match part {
+        Part::One => |input| S::part1(input).into(),
+        Part::Two => |input| S::part2(input).into(),
+    };
+    vec![Strategy::new(part, DEFAULT_STRATEGY, solve)]
+}
+
+/// The strategies of `part` picked by `choice`, in registration order.
+pub fn choose<S: Solution>(part: Part, choice: StrategyChoice) -> Vec<Strategy<S::Input>> {
+    let mut all = strategies::<S>(part);
+    match choice {
+        StrategyChoice::All => all,
+        StrategyChoice::Named(name) if all.iter().any(|s| s.name == name) => {
+            all.retain(|s| s.name == name);
+            all
+        }
+        StrategyChoice::Default | StrategyChoice::Named(_) => {
+            all.truncate(1);
+            all
+        }
+    }
+}
@@ -0,0 +1,202 @@
+//! Structured traces of the decisions a solver makes, shown by `--explain`.
+//!
+//! Solvers call `emit` wherever an intermediate step is worth seeing. Events are only built
+//! and kept while `explain` is collecting them on the current thread, so tracing costs a
+//! thread-local check otherwise.
+
+use std::{cell::RefCell, fmt, str::FromStr};
+
+use crate::Json;
+
+/// How much of a trace to keep, from a few totals to every small step.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Verbosity {
+    Summary,
+    Steps,
+    Details,
+}
+
+impl Verbosity {
+    pub fn name(self) -> &'static str {
+        match self {
+            Verbosity::Summary => "summary",
+            Verbosity::Steps => "steps",
+            Verbosity::Details => "details",
+        }
+    }
+}
+
+impl FromStr for Verbosity {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "summary" => Ok(Verbosity::Summary),
+            "steps" => Ok(Verbosity::Steps),
+            "details" => Ok(Verbosity::Details),
+            _ => Err(format!(
+                "invalid verbosity `{}`, expected summary, steps or details",
+                s
+            )),
+        }
+    }
+}
+
+/// One traced step: what happened, and the values it involved.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Event {
+    pub verbosity: Verbosity,
+    pub kind: &'static str,
+    pub fields: Vec<(&'static str, String)>,
+}
+
+impl Event {
+    pub fn new(kind: &'static str) -> Self {
+        Event {
+            verbosity: Verbosity::Summary,
+            kind,
+            fields: Vec::new(),
+        }
+    }
+
+    pub fn with(mut self, key: &'static str, value: impl fmt::Display) -> Self {
+        self.fields.push((key, value.to_string()));
+        self
+    }
+
+    pub fn to_json(&self) -> Json {
+        let fields = self
+            .fields
+            .iter()
+            .map(|&(key, ref value)| (key, Json::from(value.as_str())));
+        Json::object([
+            ("verbosity", Json::from(self.verbosity.name())),
+            ("kind", Json::from(self.kind)),
+            ("fields", Json::object(fields)),
+        ])
+    }
+}
+
+/// `kind key=value ...`, indented by verbosity so that details nest under their steps.
+impl fmt::Display for Event {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let indent = match self.verbosity {
+            Verbosity::Summary => 0,
+            Verbosity::Steps => 2,
+            Verbosity::Details => 4,
+        };
+        write!(f, "{:indent$}{}", "", self.kind, indent = indent)?;
+        for (key, value) in &self.fields {
+            write!(f, " {}={}", key, value)?;
+        }
+        Ok(())
+    }
+}
+
+struct Collector {
+    verbosity: Verbosity,
+    events: Vec<Event>,
+}
+
+thread_local! {
+    static COLLECTOR: RefCell<Option<Collector>> = const { RefCell::new(None) };
+}
+
+/// Runs `f` with tracing enabled up to `verbosity` on this thread.
+pub fn explain<T>(verbosity: Verbosity, f: impl FnOnce() -> T) -> T {
+    /// Restores the previous collector even if `f` panics.
+    struct Restore(Option<Collector>);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            COLLECTOR.set(self.0.take());
+        }
+    }
+
+    let _restore = Restore(COLLECTOR.replace(Some(Collector {
+        verbosity,
+        events: Vec::new(),
+    })));
+    f()
+}
+
+/// Whether events of `verbosity` are being collected.
+pub fn enabled(verbosity: Verbosity) -> bool {
+    COLLECTOR.with_borrow(|collector| collector.as_ref().is_some_and(|c| verbosity <= c.verbosity))
+}
+
+/// Records the event built by `event` if events of `verbosity` are being collected.
+pub fn emit(verbosity: Verbosity, event: impl FnOnce() -> Event) {
+    if !enabled(verbosity) {
+        return;
+    }
+    let event = Event {
+        verbosity,
+        ..event()
+    };
+    COLLECTOR.with_borrow_mut(|collector| {
+        if let Some(collector) = collector {
+            collector.events.push(event);
+        }
+    });
+}
+
+/// Runs `f` and returns the events it emitted, which is none unless inside `explain`.
+pub fn capture<T>(f: impl FnOnce() -> T) -> (T, Vec<Event>) {
+    let start = COLLECTOR.with_borrow(|c| c.as_ref().map_or(0, |c| c.events.len()));
+    let result = f();
+    let events = COLLECTOR.with_borrow_mut(|collector| match collector {
+        Some(collector) => collector.events.split_off(start),
+        None => Vec::new(),
+    });
+    (result, events)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn solve() -> u32 {
+        emit(Verbosity::Summary, || Event::new("total").with("value", 3));
+        for i in 0..2 {
+            emit(Verbosity::Steps, || Event::new("step").with("i", i));
+        }
+        3
+    }
+
+    #[test]
+    fn test_capture_outside_explain() {
+        assert_eq!(capture(solve), (3, Vec::new()));
+        assert!(!enabled(Verbosity::Summary));
+    }
+
+    #[test]
+    fn test_explain_filters_by_verbosity() {
+        let (_, events) = explain(Verbosity::Summary, || capture(solve));
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].to_string(), "total value=3");
+
+        let (_, events) = explain(Verbosity::Details, || capture(solve));
+        let lines: Vec<String> = events.iter().map(Event::to_string).collect();
+        assert_eq!(lines, ["total value=3", "  step i=0", "  step i=1"]);
+        assert!(!enabled(Verbosity::Summary));
+    }
+
+    #[test]
+    fn test_to_json() {
+        let event = Event {
+            verbosity: Verbosity::Steps,
+            ..Event::new("merge").with("into", "3-5")
+        };
+        assert_eq!(
+            event.to_json().to_string(),
+            r#"{"verbosity":"steps","kind":"merge","fields":{"into":"3-5"}}"#
+        );
+    }
+
+    #[test]
+    fn test_verbosity_from_str() {
+        assert_eq!("steps".parse(), Ok(Verbosity::Steps));
+        assert!("loud".parse::<Verbosity>().is_err());
+    }
+}
@@ -0,0 +1,25 @@
+//! The list of implemented days, shared by the runner and the C library so that `aoc new`
+//! registers a day in one place.
+
+use aoc_core::Puzzle;
+
+/// Every day's puzzle, in day order.
+pub const DAYS: &[Puzzle] = &[
+    Puzzle::of::<day01::Day01>(),
+    Puzzle::of::<day02::Day02>(),
+    Puzzle::of::<day03::Day03>(),
+    Puzzle::of::<day04::Day04>(),
+    Puzzle::of::<day05::Day05>(),
+    Puzzle::of::<day06::Day06>(),
+    Puzzle::of::<day07::Day07>(),
+    Puzzle::of::<day08::Day08>(),
+    Puzzle::of::<day09::Day09>(),
+    Puzzle::of::<day10::Day10>(),
+    Puzzle::of::<day11::Day11>(),
+    Puzzle::of::<day12::Day12>(),
+];
+
+/// The puzzle of `day`, if it is implemented.
+pub fn find(day: u8) -> Option<&'static Puzzle> {
+    DAYS.iter().find(|puzzle| puzzle.day == day)
+}
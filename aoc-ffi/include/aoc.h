@@ -0,0 +1,36 @@
+/* aoc.h: the Advent of Code solvers as a C library.
+ *
+ * Generated by aoc_ffi::header(); do not edit by hand. */
+
+#ifndef AOC_H
+#define AOC_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/* Status codes returned by aoc_solve. */
+#define AOC_OK                0 /* The answer was written to out_buf. */
+#define AOC_UNKNOWN_DAY       1 /* No solver for the day. */
+#define AOC_INVALID_PART      2 /* The part is not 1 or 2. */
+#define AOC_NULL_POINTER      3 /* A buffer was NULL although its length was not 0. */
+#define AOC_INVALID_UTF8      4 /* The input is not valid UTF-8. */
+#define AOC_PARSE_ERROR       5 /* The input is malformed; out_buf holds the parse error. */
+#define AOC_BUFFER_TOO_SMALL  6 /* out_buf is too small; it holds as much as fits. */
+#define AOC_PANICKED          7 /* The solver panicked; out_buf holds the panic message. */
+
+/* Solves part `part` (1 or 2) of day `day` for the `input_len` bytes of UTF-8 at
+ * `input_ptr`, and writes the answer to `out_buf` as a NUL-terminated string (empty
+ * for parts without a puzzle). On failure `out_buf` holds a message instead, cut
+ * short to fit `out_len` bytes. Either pointer may be NULL when its length is 0. */
+int32_t aoc_solve(uint32_t day, uint32_t part, const uint8_t *input_ptr,
+                  size_t input_len, char *out_buf, size_t out_len);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* AOC_H */
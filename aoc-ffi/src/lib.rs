@@ -0,0 +1,297 @@
+//! The solvers as a C library, for tools that cannot link Rust directly.
+//!
+//! `aoc_solve` runs one part of one day on an input buffer and writes the answer, or what
+//! went wrong, to a caller-owned string buffer. Its C declaration lives in `include/aoc.h`,
+//! which `header` generates; a test keeps the checked-in copy in sync.
+
+use std::{
+    ffi::c_char,
+    panic::{self, AssertUnwindSafe},
+    slice,
+};
+
+use aoc_core::{Answer, Part};
+
+/// What `aoc_solve` returns.
+#[repr(i32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    Ok = 0,
+    UnknownDay = 1,
+    InvalidPart = 2,
+    NullPointer = 3,
+    InvalidUtf8 = 4,
+    ParseError = 5,
+    BufferTooSmall = 6,
+    Panicked = 7,
+}
+
+impl Status {
+    pub const ALL: [Status; 8] = [
+        Status::Ok,
+        Status::UnknownDay,
+        Status::InvalidPart,
+        Status::NullPointer,
+        Status::InvalidUtf8,
+        Status::ParseError,
+        Status::BufferTooSmall,
+        Status::Panicked,
+    ];
+
+    /// The name of the status in `aoc.h`.
+    pub fn name(self) -> &'static str {
+        match self {
+            Status::Ok => "AOC_OK",
+            Status::UnknownDay => "AOC_UNKNOWN_DAY",
+            Status::InvalidPart => "AOC_INVALID_PART",
+            Status::NullPointer => "AOC_NULL_POINTER",
+            Status::InvalidUtf8 => "AOC_INVALID_UTF8",
+            Status::ParseError => "AOC_PARSE_ERROR",
+            Status::BufferTooSmall => "AOC_BUFFER_TOO_SMALL",
+            Status::Panicked => "AOC_PANICKED",
+        }
+    }
+
+    fn description(self) -> &'static str {
+        match self {
+            Status::Ok => "The answer was written to out_buf.",
+            Status::UnknownDay => "No solver for the day.",
+            Status::InvalidPart => "The part is not 1 or 2.",
+            Status::NullPointer => "A buffer was NULL although its length was not 0.",
+            Status::InvalidUtf8 => "The input is not valid UTF-8.",
+            Status::ParseError => "The input is malformed; out_buf holds the parse error.",
+            Status::BufferTooSmall => "out_buf is too small; it holds as much as fits.",
+            Status::Panicked => "The solver panicked; out_buf holds the panic message.",
+        }
+    }
+}
+
+/// Solves `part` of `day` for `input`: the answer as text, or why there is none.
+pub fn solve(day: u32, part: u32, input: &[u8]) -> Result<String, (Status, String)> {
+    let puzzle = u8::try_from(day)
+        .ok()
+        .and_then(aoc_days::find)
+        .ok_or_else(|| {
+            (
+                Status::UnknownDay,
+                format!("day {} is not implemented", day),
+            )
+        })?;
+    let part = match part {
+        1 => Part::One,
+        2 => Part::Two,
+        _ => {
+            return Err((
+                Status::InvalidPart,
+                format!("invalid part {}, expected 1 or 2", part),
+            ));
+        }
+    };
+    let input = str::from_utf8(input)
+        .map_err(|e| (Status::InvalidUtf8, format!("input is not UTF-8: {}", e)))?;
+
+    let report = panic::catch_unwind(AssertUnwindSafe(|| puzzle.run(input, &[part])))
+        .map_err(|payload| {
+            let message = payload
+                .downcast_ref::<&str>()
+                .copied()
+                .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+                .unwrap_or("unknown panic");
+            (Status::Panicked, message.to_string())
+        })?
+        .map_err(|e| (Status::ParseError, e.to_string()))?;
+
+    Ok(match &report.parts[0].answer {
+        Answer::None => String::new(),
+        answer => answer.to_string(),
+    })
+}
+
+/// Solves part `part` of day `day` for the `input_len` bytes at `input_ptr`, and writes
+/// the answer to `out_buf` as a NUL-terminated string. On failure `out_buf` holds a
+/// message instead, cut short if needed. Returns a `Status` code.
+///
+/// # Safety
+///
+/// `input_ptr` must point to `input_len` readable bytes and `out_buf` to `out_len`
+/// writable bytes. Either may be null when its length is 0.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn aoc_solve(
+    day: u32,
+    part: u32,
+    input_ptr: *const u8,
+    input_len: usize,
+    out_buf: *mut c_char,
+    out_len: usize,
+) -> i32 {
+    if (input_ptr.is_null() && input_len > 0) || (out_buf.is_null() && out_len > 0) {
+        return Status::NullPointer as i32;
+    }
+    let input = match input_len {
+        0 => &[][..],
+        // SAFETY: the caller guarantees `input_len` readable bytes at `input_ptr`.
+        _ => unsafe { slice::from_raw_parts(input_ptr, input_len) },
+    };
+    let out = match out_len {
+        0 => &mut [][..],
+        // SAFETY: the caller guarantees `out_len` writable bytes at `out_buf`.
+        _ => unsafe { slice::from_raw_parts_mut(out_buf.cast::<u8>(), out_len) },
+    };
+
+    let (status, text) = match solve(day, part, input) {
+        Ok(answer) => (Status::Ok, answer),
+        Err(failure) => failure,
+    };
+    match (write_truncated(out, &text), status) {
+        (false, Status::Ok) => Status::BufferTooSmall as i32,
+        (_, status) => status as i32,
+    }
+}
+
+/// Writes as much of `text` as fits in `out` followed by a NUL, without splitting a
+/// character. Returns whether all of it fit.
+fn write_truncated(out: &mut [u8], text: &str) -> bool {
+    let Some(room) = out.len().checked_sub(1) else {
+        return false;
+    };
+    let mut end = text.len().min(room);
+    while !text.is_char_boundary(end) {
+        end -= 1;
+    }
+    out[..end].copy_from_slice(&text.as_bytes()[..end]);
+    out[end] = 0;
+    end == text.len()
+}
+
+/// The C header declaring `aoc_solve` and its status codes.
+pub fn header() -> String {
+    let mut out = String::from(
+        "/* aoc.h: the Advent of Code solvers as a C library.\n\
+         \x20*\n\
+         \x20* Generated by aoc_ffi::header(); do not edit by hand. */\n\
+         \n\
+         #ifndef AOC_H\n\
+         #define AOC_H\n\
+         \n\
+         #include <stddef.h>\n\
+         #include <stdint.h>\n\
+         \n\
+         #ifdef __cplusplus\n\
+         extern \"C\" {\n\
+         #endif\n\
+         \n\
+         /* Status codes returned by aoc_solve. */\n",
+    );
+    for status in Status::ALL {
+        out.push_str(&format!(
+            "#define {:<21} {} /* {} */\n",
+            status.name(),
+            status as i32,
+            status.description()
+        ));
+    }
+    out.push_str(
+        "\n\
+         /* Solves part `part` (1 or 2) of day `day` for the `input_len` bytes of UTF-8 at\n\
+         \x20* `input_ptr`, and writes the answer to `out_buf` as a NUL-terminated string (empty\n\
+         \x20* for parts without a puzzle). On failure `out_buf` holds a message instead, cut\n\
+         \x20* short to fit `out_len` bytes. Either pointer may be NULL when its length is 0. */\n\
+         int32_t aoc_solve(uint32_t day, uint32_t part, const uint8_t *input_ptr,\n\
+         \x20                 size_t input_len, char *out_buf, size_t out_len);\n\
+         \n\
+         #ifdef __cplusplus\n\
+         }\n\
+         #endif\n\
+         \n\
+         #endif /* AOC_H */\n",
+    );
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs, path::Path, ptr};
+
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../day01/examples/example.txt");
+
+    fn call(day: u32, part: u32, input: &str, out_len: usize) -> (i32, String) {
+        let mut out = vec![0xffu8; out_len];
+        let status = unsafe {
+            aoc_solve(
+                day,
+                part,
+                input.as_ptr(),
+                input.len(),
+                out.as_mut_ptr().cast(),
+                out.len(),
+            )
+        };
+        let end = out.iter().position(|&b| b == 0).unwrap_or(out.len());
+        (status, String::from_utf8_lossy(&out[..end]).into_owned())
+    }
+
+    #[test]
+    fn test_solve() {
+        assert_eq!(call(1, 1, EXAMPLE, 64), (0, "3".to_string()));
+        assert_eq!(call(1, 2, EXAMPLE, 64), (0, "6".to_string()));
+        assert_eq!(call(12, 2, "", 64), (0, String::new()));
+    }
+
+    #[test]
+    fn test_failures() {
+        assert_eq!(
+            call(1, 1, "L68\nX30", 64),
+            (
+                Status::ParseError as i32,
+                "line 2, column 1: expected a direction `L` or `R`, found `X`".to_string()
+            )
+        );
+        assert_eq!(call(1, 1, "L68\nX30", 8), (5, "line 2,".to_string()));
+        assert_eq!(call(99, 1, EXAMPLE, 64).0, Status::UnknownDay as i32);
+        assert_eq!(call(1, 3, EXAMPLE, 64).0, Status::InvalidPart as i32);
+        assert_eq!(call(1, 1, EXAMPLE, 1), (6, String::new()));
+        assert_eq!(
+            solve(1, 1, b"L\xff"),
+            Err((
+                Status::InvalidUtf8,
+                "input is not UTF-8: invalid utf-8 sequence of 1 bytes from index 1".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn test_null_pointers() {
+        let mut out = [0u8; 8];
+        let status = unsafe { aoc_solve(1, 1, ptr::null(), 3, out.as_mut_ptr().cast(), 8) };
+        assert_eq!(status, Status::NullPointer as i32);
+        let status = unsafe { aoc_solve(1, 1, b"L1".as_ptr(), 2, ptr::null_mut(), 0) };
+        assert_eq!(status, Status::BufferTooSmall as i32);
+    }
+
+    #[test]
+    fn test_write_truncated() {
+        let mut out = [0xffu8; 4];
+        assert!(write_truncated(&mut out, "abc"));
+        assert_eq!(out, *b"abc\0");
+        // `é` is two bytes and does not fit after `ab`.
+        assert!(!write_truncated(&mut out, "abé"));
+        assert_eq!(&out[..3], b"ab\0");
+    }
+
+    /// Set `AOC_UPDATE_HEADER=1` to rewrite `include/aoc.h` after changing the API.
+    #[test]
+    fn test_header_is_up_to_date() {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("include/aoc.h");
+        if env::var_os("AOC_UPDATE_HEADER").is_some() {
+            fs::write(&path, header()).unwrap();
+        }
+        assert_eq!(
+            fs::read_to_string(&path).unwrap(),
+            header(),
+            "{} is out of date; rerun with AOC_UPDATE_HEADER=1",
+            path.display()
+        );
+    }
+}
@@ -0,0 +1,58 @@
+//! Builds `tests/solve.c` against `include/aoc.h` and the shared library, and runs it.
+#![cfg(unix)]
+
+use std::{
+    env::{self, consts},
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+/// The directory holding `libaoc_ffi.so`: the `deps` directory this test was built in.
+fn library_dir() -> PathBuf {
+    let exe = env::current_exe().unwrap();
+    let dir = exe.parent().unwrap().to_path_buf();
+    let library = format!("{}aoc_ffi{}", consts::DLL_PREFIX, consts::DLL_SUFFIX);
+    assert!(
+        dir.join(&library).exists(),
+        "{} not found in {}",
+        library,
+        dir.display()
+    );
+    dir
+}
+
+#[test]
+fn test_c_program() {
+    let crate_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let libraries = library_dir();
+    let program = Path::new(env!("CARGO_TARGET_TMPDIR")).join("aoc-ffi-solve");
+    let cc = env::var("CC").unwrap_or_else(|_| "cc".to_string());
+
+    let status = Command::new(&cc)
+        .args(["-std=c99", "-Wall", "-Wextra", "-Werror", "-o"])
+        .arg(&program)
+        .arg(crate_dir.join("tests").join("solve.c"))
+        .arg("-I")
+        .arg(crate_dir.join("include"))
+        .arg("-L")
+        .arg(&libraries)
+        .arg(format!("-Wl,-rpath,{}", libraries.display()))
+        .arg("-laoc_ffi")
+        .status()
+        .unwrap_or_else(|e| panic!("cannot run the C compiler `{}`: {}", cc, e));
+    assert!(status.success(), "compiling solve.c failed");
+
+    let output = Command::new(&program)
+        .arg(crate_dir.join("../day01/examples/example.txt"))
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "solve.c failed:\n{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert_eq!(
+        String::from_utf8_lossy(&output.stdout),
+        "all C checks passed\n"
+    );
+}
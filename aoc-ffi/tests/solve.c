@@ -0,0 +1,57 @@
+/* Calls aoc_solve the way a C tool would; exits non-zero on the first surprise. */
+
+#include <stdio.h>
+#include <string.h>
+
+#include "aoc.h"
+
+static int failures = 0;
+
+static void expect(uint32_t day, uint32_t part, const char *input, size_t out_len,
+                   int32_t status, const char *answer) {
+    char out[256];
+    memset(out, 'x', sizeof out);
+    int32_t got = aoc_solve(day, part, (const uint8_t *)input, strlen(input), out, out_len);
+    if (got != status || (answer != NULL && strcmp(out, answer) != 0)) {
+        fprintf(stderr, "day %u part %u: expected %d \"%s\", got %d \"%s\"\n", day, part,
+                status, answer ? answer : "", got, out);
+        failures++;
+    }
+}
+
+int main(int argc, char **argv) {
+    if (argc != 2) {
+        fprintf(stderr, "usage: %s <day 1 example>\n", argv[0]);
+        return 2;
+    }
+    static char example[4096];
+    FILE *file = fopen(argv[1], "rb");
+    if (file == NULL) {
+        perror(argv[1]);
+        return 2;
+    }
+    example[fread(example, 1, sizeof example - 1, file)] = '\0';
+    fclose(file);
+
+    expect(1, 1, example, 256, AOC_OK, "3");
+    expect(1, 2, example, 256, AOC_OK, "6");
+    expect(12, 2, "", 256, AOC_OK, "");
+    expect(1, 1, "L68\nX30\n", 256, AOC_PARSE_ERROR,
+           "line 2, column 1: expected a direction `L` or `R`, found `X`");
+    expect(1, 1, "L68\nX30\n", 7, AOC_PARSE_ERROR, "line 2");
+    expect(1, 1, example, 1, AOC_BUFFER_TOO_SMALL, "");
+    expect(0, 1, example, 256, AOC_UNKNOWN_DAY, NULL);
+    expect(1, 3, example, 256, AOC_INVALID_PART, NULL);
+    expect(1, 1, "L\xff", 256, AOC_INVALID_UTF8, NULL);
+
+    char out[8];
+    if (aoc_solve(1, 1, NULL, 4, out, sizeof out) != AOC_NULL_POINTER) {
+        fprintf(stderr, "NULL input was accepted\n");
+        failures++;
+    }
+
+    if (failures == 0) {
+        printf("all C checks passed\n");
+    }
+    return failures == 0 ? 0 : 1;
+}
@@ -0,0 +1,330 @@
+use std::{
+    collections::BTreeMap,
+    env, fmt, fs, io,
+    path::{Path, PathBuf},
+};
+
+use aoc_core::{Answer, INPUT_DIR_VAR, Line, ParseError, Part, lines};
+
+use crate::summary::Outcome;
+use crate::table::{Align, Table};
+
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+/// `answers.toml` next to the inputs: under `$AOC_INPUT_DIR` or the current directory.
+pub fn default_path() -> PathBuf {
+    match env::var_os(INPUT_DIR_VAR) {
+        Some(dir) => PathBuf::from(dir).join(ANSWERS_FILE),
+        None => PathBuf::from(ANSWERS_FILE),
+    }
+}
+
+/// Known-good answers per day and part, stored as a small TOML file:
+///
+/// ```toml
+/// [day01]
+/// part1 = 1097
+/// part2 = "text answers are quoted"
+/// ```
+#[derive(Debug, Default, PartialEq)]
+pub struct Answers {
+    expected: BTreeMap<(u8, u8), Answer>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Status {
+    Pass,
+    Fail {
+        expected: String,
+    },
+    Missing,
+    /// Missing, and now recorded from the computed answer.
+    Recorded,
+    /// The part has no answer to check.
+    Skipped,
+}
+
+impl Status {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Status::Pass => "PASS",
+            Status::Fail { .. } => "FAIL",
+            Status::Missing => "MISSING",
+            Status::Recorded => "RECORDED",
+            Status::Skipped => "-",
+        }
+    }
+}
+
+impl Answers {
+    /// Loads the answers at `path`; a file that does not exist yet has no answers.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        match fs::read_to_string(path) {
+            Ok(text) => Answers::parse(&text).map_err(|e| e.render(&text)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Answers::default()),
+            Err(e) => Err(e.to_string()),
+        }
+    }
+
+    pub fn parse(text: &str) -> Result<Self, ParseError> {
+        let mut answers = Answers::default();
+        let mut day = None;
+
+        for line in lines(text) {
+            let content = line.text.trim();
+            if content.is_empty() || content.starts_with('#') {
+                continue;
+            }
+
+            if let Some(header) = content.strip_prefix('[') {
+                let name = header
+                    .strip_suffix(']')
+                    .ok_or_else(|| line.error(content, "a table header `[dayNN]`"))?;
+                day = Some(parse_key(&line, name, "day", "a table header `[dayNN]`")?);
+                continue;
+            }
+
+            let (key, value) = content
+                .split_once('=')
+                .ok_or_else(|| line.error(content, "`partN = answer`"))?;
+            let day = day.ok_or_else(|| line.error(content, "a `[dayNN]` header first"))?;
+            let part = parse_key(&line, key.trim(), "part", "`part1` or `part2`")?;
+            if !(1..=2).contains(&part) {
+                return Err(line.error(key.trim(), "`part1` or `part2`"));
+            }
+            answers
+                .expected
+                .insert((day, part), Answer::parse(&line, value.trim())?);
+        }
+
+        Ok(answers)
+    }
+
+    pub fn check(&self, day: u8, part: Part, answer: &Answer) -> Status {
+        if *answer == Answer::None {
+            return Status::Skipped;
+        }
+        match self.expected.get(&(day, part.number())) {
+            Some(expected) if expected.matches(answer) => Status::Pass,
+            // Text stays quoted, so an expected `"3"` does not read like the integer 3.
+            Some(Answer::Text(text)) => Status::Fail {
+                expected: format!("\"{}\"", text),
+            },
+            Some(expected) => Status::Fail {
+                expected: expected.to_string(),
+            },
+            None => Status::Missing,
+        }
+    }
+
+    pub fn record(&mut self, day: u8, part: Part, answer: Answer) {
+        self.expected.insert((day, part.number()), answer);
+    }
+}
+
+fn parse_key(line: &Line, key: &str, prefix: &str, expected: &str) -> Result<u8, ParseError> {
+    key.strip_prefix(prefix)
+        .and_then(|number| number.parse().ok())
+        .ok_or_else(|| line.error(key, expected))
+}
+
+impl fmt::Display for Answers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "# Known-good answers checked by `aoc verify`.")?;
+
+        let mut day = None;
+        for (&(d, part), answer) in &self.expected {
+            if day != Some(d) {
+                writeln!(f)?;
+                writeln!(f, "[day{:02}]", d)?;
+                day = Some(d);
+            }
+            write!(f, "part{} = ", part)?;
+            match answer {
+                Answer::Text(text) => {
+                    let escaped = text
+                        .replace('\\', "\\\\")
+                        .replace('"', "\\\"")
+                        .replace('\n', "\\n")
+                        .replace('\t', "\\t");
+                    writeln!(f, "\"{}\"", escaped)?;
+                }
+                answer => writeln!(f, "{}", answer)?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// The verification result for one part.
+#[derive(Debug)]
+pub struct Check {
+    pub day: u8,
+    pub part: Part,
+    pub answer: Answer,
+    pub status: Status,
+}
+
+/// Checks every solved part against `answers`, recording missing ones when `record` is set,
+/// and returns one check per solved part.
+pub fn verify(outcomes: &[Outcome], answers: &mut Answers, record: bool) -> Vec<Check> {
+    let mut checks = Vec::new();
+    for outcome in outcomes {
+        if let Outcome::Solved(report) = outcome {
+            for part in &report.parts {
+                let mut status = answers.check(report.day, part.part, &part.answer);
+                if record && status == Status::Missing {
+                    answers.record(report.day, part.part, part.answer.clone());
+                    status = Status::Recorded;
+                }
+                checks.push(Check {
+                    day: report.day,
+                    part: part.part,
+                    answer: part.answer.clone(),
+                    status,
+                });
+            }
+        }
+    }
+    checks
+}
+
+pub fn print_table(checks: &[Check], outcomes: &[Outcome]) {
+    let mut table = Table::new(&[
+        ("Day", Align::Right),
+        ("Part", Align::Right),
+        ("Answer", Align::Left),
+        ("Expected", Align::Left),
+        ("Status", Align::Left),
+    ]);
+
+    for check in checks {
+        let expected = match &check.status {
+            Status::Fail { expected } => expected.clone(),
+            Status::Pass => check.answer.to_string(),
+            _ => "-".to_string(),
+        };
+        table.push(vec![
+            check.day.to_string(),
+            check.part.number().to_string(),
+            check.answer.to_string(),
+            expected,
+            check.status.label().to_string(),
+        ]);
+    }
+    for outcome in outcomes {
+        if let Outcome::Failed { day, reason, .. } = outcome {
+            table.push(vec![
+                day.to_string(),
+                "-".to_string(),
+                "-".to_string(),
+                "-".to_string(),
+                format!("FAILED: {}", reason),
+            ]);
+        }
+    }
+
+    print!("{}", table);
+
+    let count = |label: &str| checks.iter().filter(|c| c.status.label() == label).count();
+    println!();
+    println!(
+        "{} passed, {} failed, {} missing, {} recorded, {} day(s) failed to run",
+        count("PASS"),
+        count("FAIL"),
+        count("MISSING"),
+        count("RECORDED"),
+        outcomes.iter().filter(|o| o.is_failure()).count()
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ANSWERS: &str = "\
+# comment
+[day01]
+part1 = 3
+part2 = 6 # trailing comment
+
+[day12]
+part1 = \"a \\\"quoted\\\" answer\"
+";
+
+    #[test]
+    fn test_parse() {
+        let answers = Answers::parse(ANSWERS).unwrap();
+        assert_eq!(
+            answers.check(1, Part::One, &Answer::Unsigned(3)),
+            Status::Pass
+        );
+        assert_eq!(
+            answers.check(1, Part::Two, &Answer::Signed(6)),
+            Status::Pass
+        );
+        assert_eq!(
+            answers.check(1, Part::Two, &Answer::Signed(7)),
+            Status::Fail {
+                expected: "6".to_string()
+            }
+        );
+        assert_eq!(
+            answers.check(
+                12,
+                Part::One,
+                &Answer::Text("a \"quoted\" answer".to_string())
+            ),
+            Status::Pass
+        );
+        assert_eq!(
+            answers.check(2, Part::One, &Answer::Unsigned(1)),
+            Status::Missing
+        );
+        assert_eq!(answers.check(12, Part::Two, &Answer::None), Status::Skipped);
+    }
+
+    #[test]
+    fn test_check_compares_types() {
+        let answers = Answers::parse("[day01]\npart1 = \"3\"\npart2 = 3").unwrap();
+        assert_eq!(
+            answers.check(1, Part::One, &Answer::Unsigned(3)),
+            Status::Fail {
+                expected: "\"3\"".to_string()
+            }
+        );
+        assert_eq!(
+            answers.check(1, Part::Two, &Answer::Text("3".to_string())),
+            Status::Fail {
+                expected: "3".to_string()
+            }
+        );
+        assert_eq!(
+            answers.check(1, Part::Two, &Answer::Signed(-3)),
+            Status::Fail {
+                expected: "3".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            Answers::parse("[day01]\npart1 = 3x"),
+            Err(ParseError::new(2, 9, "3x", "an integer or a quoted string"))
+        );
+        assert!(Answers::parse("part1 = 3").is_err());
+        assert!(Answers::parse("[day01]\npart3 = 3").is_err());
+        assert!(Answers::parse("[day01\npart1 = 3").is_err());
+        assert!(Answers::parse("[day01]\npart1 = \"open").is_err());
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let mut answers = Answers::parse(ANSWERS).unwrap();
+        answers.record(3, Part::Two, Answer::Signed(-42));
+        answers.record(5, Part::One, Answer::Text("tab\there".to_string()));
+        assert_eq!(Answers::parse(&answers.to_string()).unwrap(), answers);
+    }
+}
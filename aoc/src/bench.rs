@@ -0,0 +1,307 @@
+use std::time::Duration;
+
+use aoc_core::{Json, ParseError, Part, Puzzle};
+
+use crate::summary::format_duration;
+use crate::table::{Align, Table};
+
+/// Timing statistics over repeated runs of one step.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stats {
+    pub iterations: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub p95: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+        let n = sorted.len();
+
+        let median = if n.is_multiple_of(2) {
+            (sorted[n / 2 - 1] + sorted[n / 2]) / 2
+        } else {
+            sorted[n / 2]
+        };
+        // Nearest-rank percentile.
+        let p95 = sorted[(n * 95).div_ceil(100) - 1];
+
+        Some(Stats {
+            iterations: n,
+            min: sorted[0],
+            median,
+            mean: sorted.iter().sum::<Duration>() / n as u32,
+            p95,
+        })
+    }
+}
+
+/// Statistics for one step (`parse`, `part1` or `part2`) of one day.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Measurement {
+    pub day: u8,
+    pub step: String,
+    pub stats: Stats,
+}
+
+pub fn step_name(part: Part) -> String {
+    format!("part{}", part.number())
+}
+
+/// Runs a day `iterations` times after one warm-up run, timing the parse and each part.
+pub fn measure(
+    puzzle: &Puzzle,
+    input: &str,
+    parts: &[Part],
+    iterations: usize,
+) -> Result<Vec<Measurement>, ParseError> {
+    puzzle.run(input, parts)?;
+
+    let mut parse_samples = Vec::with_capacity(iterations);
+    let mut part_samples = vec![Vec::with_capacity(iterations); parts.len()];
+    for _ in 0..iterations {
+        let report = puzzle.run(input, parts)?;
+        parse_samples.push(report.parse_time);
+        for (samples, part) in part_samples.iter_mut().zip(&report.parts) {
+            samples.push(part.time);
+        }
+    }
+
+    let steps = std::iter::once(("parse".to_string(), parse_samples))
+        .chain(parts.iter().map(|&part| step_name(part)).zip(part_samples));
+
+    Ok(steps
+        .filter_map(|(step, samples)| {
+            Stats::from_samples(&samples).map(|stats| Measurement {
+                day: puzzle.day,
+                step,
+                stats,
+            })
+        })
+        .collect())
+}
+
+/// How a measurement's median compares with the same step in a baseline.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Comparison {
+    pub baseline_median: Duration,
+    pub change_percent: f64,
+    pub regressed: bool,
+}
+
+pub fn compare(
+    measurement: &Measurement,
+    baseline: &[Measurement],
+    threshold_percent: f64,
+) -> Option<Comparison> {
+    let base = baseline
+        .iter()
+        .find(|b| b.day == measurement.day && b.step == measurement.step)?;
+
+    let base_ns = base.stats.median.as_nanos() as f64;
+    let current_ns = measurement.stats.median.as_nanos() as f64;
+    let change_percent = if base_ns == 0.0 {
+        0.0
+    } else {
+        (current_ns - base_ns) / base_ns * 100.0
+    };
+
+    Some(Comparison {
+        baseline_median: base.stats.median,
+        change_percent,
+        regressed: change_percent > threshold_percent,
+    })
+}
+
+pub fn to_json(measurements: &[Measurement]) -> Json {
+    let results = measurements
+        .iter()
+        .map(|m| {
+            Json::object([
+                ("day", Json::from(m.day as u64)),
+                ("step", Json::from(m.step.as_str())),
+                ("iterations", Json::from(m.stats.iterations)),
+                ("min_ns", nanos(m.stats.min)),
+                ("median_ns", nanos(m.stats.median)),
+                ("mean_ns", nanos(m.stats.mean)),
+                ("p95_ns", nanos(m.stats.p95)),
+            ])
+        })
+        .collect();
+
+    Json::object([
+        ("version", Json::from(1u64)),
+        ("results", Json::Array(results)),
+    ])
+}
+
+fn nanos(duration: Duration) -> Json {
+    Json::Int(duration.as_nanos() as i128)
+}
+
+pub fn from_json(json: &Json) -> Result<Vec<Measurement>, String> {
+    let results = json
+        .get("results")
+        .and_then(Json::as_array)
+        .ok_or("baseline has no `results` array")?;
+
+    results
+        .iter()
+        .map(|result| {
+            let duration = |key: &str| int(result, key).map(Duration::from_nanos);
+
+            Ok(Measurement {
+                day: int(result, "day")?,
+                step: result
+                    .get("step")
+                    .and_then(Json::as_str)
+                    .ok_or("baseline result is missing `step`")?
+                    .to_string(),
+                stats: Stats {
+                    iterations: int(result, "iterations")?,
+                    min: duration("min_ns")?,
+                    median: duration("median_ns")?,
+                    mean: duration("mean_ns")?,
+                    p95: duration("p95_ns")?,
+                },
+            })
+        })
+        .collect()
+}
+
+/// The integer `key` of a baseline result, which must fit a `T`.
+fn int<T: TryFrom<i128>>(result: &Json, key: &str) -> Result<T, String> {
+    let value = result
+        .get(key)
+        .and_then(Json::as_i128)
+        .ok_or(format!("baseline result is missing `{}`", key))?;
+    T::try_from(value).map_err(|_| format!("baseline result has `{}` out of range: {}", key, value))
+}
+
+pub fn print_table(measurements: &[Measurement], baseline: Option<&[Measurement]>, threshold: f64) {
+    let mut columns = vec![
+        ("Day", Align::Right),
+        ("Step", Align::Left),
+        ("Iters", Align::Right),
+        ("Min", Align::Right),
+        ("Median", Align::Right),
+        ("Mean", Align::Right),
+        ("p95", Align::Right),
+    ];
+    if baseline.is_some() {
+        columns.extend([
+            ("Baseline", Align::Right),
+            ("Change", Align::Right),
+            ("", Align::Left),
+        ]);
+    }
+
+    let mut table = Table::new(&columns);
+    for m in measurements {
+        let mut row = vec![
+            m.day.to_string(),
+            m.step.clone(),
+            m.stats.iterations.to_string(),
+            format_duration(m.stats.min),
+            format_duration(m.stats.median),
+            format_duration(m.stats.mean),
+            format_duration(m.stats.p95),
+        ];
+        if let Some(baseline) = baseline {
+            match compare(m, baseline, threshold) {
+                Some(c) => row.extend([
+                    format_duration(c.baseline_median),
+                    format!("{:+.1}%", c.change_percent),
+                    if c.regressed { "REGRESSION" } else { "" }.to_string(),
+                ]),
+                None => row.extend(["-".to_string(), "-".to_string(), "new".to_string()]),
+            }
+        }
+        table.push(row);
+    }
+
+    print!("{}", table);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(values: &[u64]) -> Vec<Duration> {
+        values.iter().map(|&v| Duration::from_millis(v)).collect()
+    }
+
+    fn measurement(step: &str, median_ms: u64) -> Measurement {
+        let d = Duration::from_millis(median_ms);
+        Measurement {
+            day: 3,
+            step: step.to_string(),
+            stats: Stats {
+                iterations: 5,
+                min: d,
+                median: d,
+                mean: d,
+                p95: d,
+            },
+        }
+    }
+
+    #[test]
+    fn test_stats() {
+        let stats = Stats::from_samples(&ms(&[5, 1, 4, 2, 3])).unwrap();
+        assert_eq!(stats.iterations, 5);
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_millis(3));
+        assert_eq!(stats.mean, Duration::from_millis(3));
+        assert_eq!(stats.p95, Duration::from_millis(5));
+
+        let stats = Stats::from_samples(&ms(&[4, 1, 3, 2])).unwrap();
+        assert_eq!(stats.median, Duration::from_micros(2500));
+        assert!(Stats::from_samples(&[]).is_none());
+    }
+
+    #[test]
+    fn test_compare() {
+        let baseline = vec![measurement("part1", 100), measurement("part2", 100)];
+
+        let faster = compare(&measurement("part1", 95), &baseline, 10.0).unwrap();
+        assert!(!faster.regressed);
+        assert!((faster.change_percent + 5.0).abs() < 1e-9);
+
+        let slower = compare(&measurement("part2", 120), &baseline, 10.0).unwrap();
+        assert!(slower.regressed);
+
+        assert!(compare(&measurement("parse", 1), &baseline, 10.0).is_none());
+    }
+
+    #[test]
+    fn test_json_roundtrip() {
+        let measurements = vec![measurement("parse", 2), measurement("part1", 7)];
+        let json = Json::parse(&to_json(&measurements).to_string()).unwrap();
+        assert_eq!(from_json(&json).unwrap(), measurements);
+        assert!(from_json(&Json::Null).is_err());
+
+        let baseline = |day: &str, min: &str| {
+            Json::parse(&format!(
+                r#"{{"results":[{{"day":{},"step":"part1","iterations":5,"min_ns":{},
+                "median_ns":1,"mean_ns":1,"p95_ns":1}}]}}"#,
+                day, min
+            ))
+            .unwrap()
+        };
+        assert_eq!(from_json(&baseline("3", "1")).unwrap()[0].day, 3);
+        assert_eq!(
+            from_json(&baseline("259", "1")),
+            Err("baseline result has `day` out of range: 259".to_string())
+        );
+        assert!(from_json(&baseline("3", "-1")).is_err());
+        assert!(from_json(&baseline("3", "18446744073709551616")).is_err());
+    }
+}
@@ -0,0 +1,655 @@
+use std::{path::PathBuf, time::Duration};
+
+use aoc_core::{Format, InputSource, Part, StrategyChoice, Verbosity, cancel};
+
+pub const USAGE: &str = "Usage:
+  aoc run <day> [--part <1|2>] [--input <path|->] [--format <text|json|csv>]
+          [--strategy <name> | --cross-check] [--explain <summary|steps|details>]
+          [--jobs <n>] [--timeout <seconds>]
+  aoc run --all [--part <1|2>] [--format <text|json|csv>]
+          [--strategy <name> | --cross-check] [--explain <summary|steps|details>]
+          [--jobs <n>] [--timeout <seconds>]
+  aoc bench <day>|--all [--part <1|2>] [--iterations <n>] [--save <file.json>]
+            [--compare <file.json>] [--threshold <percent>]
+  aoc verify [<day>|--all] [--part <1|2>] [--answers <file.toml>] [--record]
+  aoc fuzz <day>|--all [--iterations <n>] [--seed <n>]
+  aoc render <day> [--input <path|->] [--ppm <dir>] [--scale <n>] [--delay <ms>]
+  aoc new <day>
+  aoc serve [--port <n>] [--connections <n>] [--timeout <seconds>]
+  aoc help
+
+run solves each part on its own thread, --jobs at a time (default: one per CPU),
+and gives up on parts still running after --timeout.
+Inputs default to dayNN/input.txt under $AOC_INPUT_DIR or the current directory,
+and verify reads answers.toml from the same place. fuzz mutates the examples
+and dayNN/fuzz/corpus, and saves inputs that crash the parser to dayNN/fuzz/crashes.
+render plays a day's animation in the terminal, or writes its frames as PPM images.
+new creates the dayNN crate from a template and registers it in the workspace and
+in aoc-days, which the runner and the C library share; run it from the workspace root.
+serve answers POST /day/{n}/part/{p}, with the input as the body, with JSON on
+127.0.0.1 (default port 8080), handling --connections at once (default 16) and
+giving up on solves still running after --timeout (default 10 seconds).";
+
+#[derive(Debug, PartialEq)]
+pub enum Command {
+    Run(RunArgs, RunOptions),
+    Bench(BenchArgs),
+    Verify(VerifyArgs),
+    Fuzz(FuzzArgs),
+    Render(RenderArgs),
+    New(u8),
+    Serve(ServeArgs),
+    Help,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct RunArgs {
+    pub days: Selection,
+    pub parts: Vec<Part>,
+    pub input: InputSource,
+}
+
+/// Options of `aoc run` that the other commands do not take.
+#[derive(Debug, PartialEq)]
+pub struct RunOptions {
+    pub format: Format,
+    pub strategy: Option<String>,
+    /// Run every strategy of each part and fail if their answers differ.
+    pub cross_check: bool,
+    /// Trace each part's intermediate steps up to this verbosity.
+    pub explain: Option<Verbosity>,
+    /// Parts solved at once; defaults to the available parallelism.
+    pub jobs: Option<usize>,
+    /// Time after which a part is reported as timed out.
+    pub timeout: Option<Duration>,
+}
+
+impl RunOptions {
+    pub fn choice(&self) -> StrategyChoice<'_> {
+        StrategyChoice::from_flags(self.strategy.as_deref(), self.cross_check)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct BenchArgs {
+    pub run: RunArgs,
+    pub iterations: usize,
+    pub save: Option<PathBuf>,
+    pub compare: Option<PathBuf>,
+    /// Slowdown of the median, in percent, above which a step counts as a regression.
+    pub threshold: f64,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct VerifyArgs {
+    pub run: RunArgs,
+    /// Defaults to `answers.toml` next to the inputs.
+    pub answers: Option<PathBuf>,
+    /// Write computed answers for parts that have none recorded yet.
+    pub record: bool,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct FuzzArgs {
+    pub days: Selection,
+    pub iterations: usize,
+    /// Seeds the mutations; defaults to one derived from the clock.
+    pub seed: Option<u64>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct RenderArgs {
+    pub day: u8,
+    pub input: InputSource,
+    /// Directory to write the frames to, instead of playing them in the terminal.
+    pub ppm: Option<PathBuf>,
+    /// Side of each cell in the PPM images, in pixels.
+    pub scale: usize,
+    /// Pause between frames in the terminal.
+    pub delay: Duration,
+}
+
+/// Serve the solvers over HTTP.
+#[derive(Debug, PartialEq)]
+pub struct ServeArgs {
+    pub port: u16,
+    /// Connections handled at once; later ones wait to be accepted.
+    pub connections: usize,
+    /// Time after which a solve is answered as timed out.
+    pub timeout: Duration,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Selection {
+    All,
+    Day(u8),
+}
+
+pub fn parse<I: IntoIterator<Item = String>>(args: I) -> Result<Command, String> {
+    let mut args = args.into_iter();
+
+    match args.next().as_deref() {
+        Some("run") => parse_run_command(args),
+        Some("bench") => parse_bench(args).map(Command::Bench),
+        Some("verify") => parse_verify(args).map(Command::Verify),
+        Some("fuzz") => parse_fuzz(args).map(Command::Fuzz),
+        Some("render") => parse_render(args).map(Command::Render),
+        Some("new") => parse_new(args).map(Command::New),
+        Some("serve") => parse_serve(args).map(Command::Serve),
+        Some("help" | "--help" | "-h") | None => Ok(Command::Help),
+        Some(other) => Err(format!("unknown command `{}`", other)),
+    }
+}
+
+/// Parses the options shared by all day-running commands, passing any other flag to `extra`,
+/// which returns whether it consumed it. `default_days` applies when no day is given.
+fn parse_run<I, F>(
+    mut args: I,
+    default_days: Option<Selection>,
+    mut extra: F,
+) -> Result<RunArgs, String>
+where
+    I: Iterator<Item = String>,
+    F: FnMut(&str, &mut I) -> Result<bool, String>,
+{
+    let mut days = None;
+    let mut parts = Part::ALL.to_vec();
+    let mut input = InputSource::Default;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--all" => set_days(&mut days, Selection::All)?,
+            "--part" | "-p" => parts = vec![parse_part(&value(&mut args, &arg)?)?],
+            "--input" | "-i" => input = InputSource::from_arg(&value(&mut args, &arg)?),
+            _ if arg.starts_with('-') => {
+                if !extra(&arg, &mut args)? {
+                    return Err(format!("unknown option `{}`", arg));
+                }
+            }
+            _ => set_days(&mut days, Selection::Day(parse_day(&arg)?))?,
+        }
+    }
+
+    let days = days
+        .or(default_days)
+        .ok_or("expected a day number or --all")?;
+    if days == Selection::All && input != InputSource::Default {
+        return Err("--input can only be used with a single day".to_string());
+    }
+
+    Ok(RunArgs { days, parts, input })
+}
+
+fn parse_run_command<I: Iterator<Item = String>>(args: I) -> Result<Command, String> {
+    let mut options = RunOptions {
+        format: Format::Text,
+        strategy: None,
+        cross_check: false,
+        explain: None,
+        jobs: None,
+        timeout: None,
+    };
+
+    let run = parse_run(args, None, |arg, args| {
+        match arg {
+            "--format" | "-f" => options.format = value(args, arg)?.parse()?,
+            "--strategy" | "-s" => options.strategy = Some(value(args, arg)?),
+            "--cross-check" => options.cross_check = true,
+            "--explain" | "-e" => options.explain = Some(value(args, arg)?.parse()?),
+            "--jobs" | "-j" => {
+                let jobs = parse_number(&value(args, arg)?, arg)?;
+                if jobs == 0 {
+                    return Err("--jobs must be at least 1".to_string());
+                }
+                options.jobs = Some(jobs);
+            }
+            "--timeout" => options.timeout = Some(cancel::parse_timeout(&value(args, arg)?)?),
+            _ => return Ok(false),
+        }
+        Ok(true)
+    })?;
+
+    if options.strategy.is_some() && options.cross_check {
+        return Err("--strategy and --cross-check cannot be combined".to_string());
+    }
+
+    Ok(Command::Run(run, options))
+}
+
+fn parse_bench<I: Iterator<Item = String>>(args: I) -> Result<BenchArgs, String> {
+    let mut iterations = 10;
+    let mut save = None;
+    let mut compare = None;
+    let mut threshold: f64 = 10.0;
+
+    let run = parse_run(args, None, |arg, args| {
+        match arg {
+            "--iterations" | "-n" => {
+                iterations = parse_number(&value(args, arg)?, arg)?;
+                if iterations == 0 {
+                    return Err("--iterations must be at least 1".to_string());
+                }
+            }
+            "--save" => save = Some(PathBuf::from(value(args, arg)?)),
+            "--compare" => compare = Some(PathBuf::from(value(args, arg)?)),
+            "--threshold" => {
+                threshold = parse_number(&value(args, arg)?, arg)?;
+                if !threshold.is_finite() {
+                    return Err("--threshold must be a finite percentage".to_string());
+                }
+            }
+            _ => return Ok(false),
+        }
+        Ok(true)
+    })?;
+
+    Ok(BenchArgs {
+        run,
+        iterations,
+        save,
+        compare,
+        threshold,
+    })
+}
+
+fn parse_verify<I: Iterator<Item = String>>(args: I) -> Result<VerifyArgs, String> {
+    let mut answers = None;
+    let mut record = false;
+
+    let run = parse_run(args, Some(Selection::All), |arg, args| {
+        match arg {
+            "--answers" => answers = Some(PathBuf::from(value(args, arg)?)),
+            "--record" => record = true,
+            _ => return Ok(false),
+        }
+        Ok(true)
+    })?;
+
+    Ok(VerifyArgs {
+        run,
+        answers,
+        record,
+    })
+}
+
+fn parse_fuzz<I: Iterator<Item = String>>(mut args: I) -> Result<FuzzArgs, String> {
+    let mut days = None;
+    let mut iterations = 10_000;
+    let mut seed = None;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--all" => set_days(&mut days, Selection::All)?,
+            "--iterations" | "-n" => iterations = parse_number(&value(&mut args, &arg)?, &arg)?,
+            "--seed" => seed = Some(parse_number(&value(&mut args, &arg)?, &arg)?),
+            _ if arg.starts_with('-') => return Err(format!("unknown option `{}`", arg)),
+            _ => set_days(&mut days, Selection::Day(parse_day(&arg)?))?,
+        }
+    }
+
+    Ok(FuzzArgs {
+        days: days.ok_or("expected a day number or --all")?,
+        iterations,
+        seed,
+    })
+}
+
+fn parse_render<I: Iterator<Item = String>>(mut args: I) -> Result<RenderArgs, String> {
+    let mut day = None;
+    let mut input = InputSource::Default;
+    let mut ppm = None;
+    let mut scale = 4;
+    let mut delay = Duration::from_millis(100);
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--input" | "-i" => input = InputSource::from_arg(&value(&mut args, &arg)?),
+            "--ppm" => ppm = Some(PathBuf::from(value(&mut args, &arg)?)),
+            "--scale" => {
+                scale = parse_number(&value(&mut args, &arg)?, &arg)?;
+                if scale == 0 {
+                    return Err("--scale must be at least 1".to_string());
+                }
+            }
+            "--delay" => {
+                delay = Duration::from_millis(parse_number(&value(&mut args, &arg)?, &arg)?)
+            }
+            _ if arg.starts_with('-') && arg != "-" => {
+                return Err(format!("unknown option `{}`", arg));
+            }
+            _ if day.is_some() => return Err(format!("unexpected argument `{}`", arg)),
+            _ => day = Some(parse_day(&arg)?),
+        }
+    }
+
+    Ok(RenderArgs {
+        day: day.ok_or("expected a day number")?,
+        input,
+        ppm,
+        scale,
+        delay,
+    })
+}
+
+fn parse_new<I: Iterator<Item = String>>(mut args: I) -> Result<u8, String> {
+    let day = parse_day(&args.next().ok_or("expected a day number")?)?;
+    match args.next() {
+        Some(arg) => Err(format!("unexpected argument `{}`", arg)),
+        None => Ok(day),
+    }
+}
+
+fn parse_serve<I: Iterator<Item = String>>(mut args: I) -> Result<ServeArgs, String> {
+    let mut serve = ServeArgs {
+        port: 8080,
+        connections: 16,
+        timeout: Duration::from_secs(10),
+    };
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--port" => serve.port = parse_number(&value(&mut args, &arg)?, &arg)?,
+            "--connections" => {
+                serve.connections = parse_number(&value(&mut args, &arg)?, &arg)?;
+                if serve.connections == 0 {
+                    return Err("--connections must be at least 1".to_string());
+                }
+            }
+            "--timeout" => serve.timeout = cancel::parse_timeout(&value(&mut args, &arg)?)?,
+            _ if arg.starts_with('-') => return Err(format!("unknown option `{}`", arg)),
+            _ => return Err(format!("unexpected argument `{}`", arg)),
+        }
+    }
+    Ok(serve)
+}
+
+fn value<I: Iterator<Item = String>>(args: &mut I, flag: &str) -> Result<String, String> {
+    args.next()
+        .ok_or_else(|| format!("missing value for {}", flag))
+}
+
+fn parse_number<T: std::str::FromStr>(value: &str, flag: &str) -> Result<T, String> {
+    value
+        .parse()
+        .map_err(|_| format!("invalid value `{}` for {}", value, flag))
+}
+
+fn set_days(days: &mut Option<Selection>, selection: Selection) -> Result<(), String> {
+    if days.is_some() {
+        return Err("expected a single day number or --all".to_string());
+    }
+    *days = Some(selection);
+    Ok(())
+}
+
+pub fn parse_day(value: &str) -> Result<u8, String> {
+    match value.parse::<u8>() {
+        Ok(day) if (1..=25).contains(&day) => Ok(day),
+        _ => Err(format!("invalid day `{}`", value)),
+    }
+}
+
+pub fn parse_part(value: &str) -> Result<Part, String> {
+    match value {
+        "1" => Ok(Part::One),
+        "2" => Ok(Part::Two),
+        _ => Err(format!("invalid part `{}`, expected 1 or 2", value)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(line: &str) -> Vec<String> {
+        line.split_whitespace().map(str::to_string).collect()
+    }
+
+    #[test]
+    fn test_parse_run_day() {
+        assert_eq!(
+            parse(args("run 7 --part 2")),
+            Ok(Command::Run(
+                RunArgs {
+                    days: Selection::Day(7),
+                    parts: vec![Part::Two],
+                    input: InputSource::Default,
+                },
+                RunOptions {
+                    format: Format::Text,
+                    strategy: None,
+                    cross_check: false,
+                    explain: None,
+                    jobs: None,
+                    timeout: None,
+                }
+            ))
+        );
+    }
+
+    #[test]
+    fn test_parse_run_all() {
+        assert_eq!(
+            parse(args("run --all")),
+            Ok(Command::Run(
+                RunArgs {
+                    days: Selection::All,
+                    parts: vec![Part::One, Part::Two],
+                    input: InputSource::Default,
+                },
+                RunOptions {
+                    format: Format::Text,
+                    strategy: None,
+                    cross_check: false,
+                    explain: None,
+                    jobs: None,
+                    timeout: None,
+                }
+            ))
+        );
+    }
+
+    #[test]
+    fn test_parse_run_input() {
+        assert_eq!(
+            parse(args("run 1 --input -")),
+            Ok(Command::Run(
+                RunArgs {
+                    days: Selection::Day(1),
+                    parts: vec![Part::One, Part::Two],
+                    input: InputSource::Stdin,
+                },
+                RunOptions {
+                    format: Format::Text,
+                    strategy: None,
+                    cross_check: false,
+                    explain: None,
+                    jobs: None,
+                    timeout: None,
+                }
+            ))
+        );
+    }
+
+    #[test]
+    fn test_parse_run_format() {
+        assert_eq!(
+            parse(args("run --all --format csv")),
+            Ok(Command::Run(
+                RunArgs {
+                    days: Selection::All,
+                    parts: vec![Part::One, Part::Two],
+                    input: InputSource::Default,
+                },
+                RunOptions {
+                    format: Format::Csv,
+                    strategy: None,
+                    cross_check: false,
+                    explain: None,
+                    jobs: None,
+                    timeout: None,
+                }
+            ))
+        );
+        assert!(parse(args("run 1 --format yaml")).is_err());
+        assert!(parse(args("bench 1 --format json")).is_err());
+    }
+
+    #[test]
+    fn test_parse_run_strategy() {
+        let Ok(Command::Run(_, options)) = parse(args("run 2 --strategy optimized")) else {
+            panic!("expected a run command");
+        };
+        assert_eq!(options.choice(), StrategyChoice::Named("optimized"));
+
+        let Ok(Command::Run(_, options)) = parse(args("run --all --cross-check")) else {
+            panic!("expected a run command");
+        };
+        assert_eq!(options.choice(), StrategyChoice::All);
+
+        assert!(parse(args("run 2 -s brute --cross-check")).is_err());
+
+        let Ok(Command::Run(_, options)) = parse(args("run 5 --explain steps")) else {
+            panic!("expected a run command");
+        };
+        assert_eq!(options.explain, Some(Verbosity::Steps));
+        assert!(parse(args("run 5 --explain everything")).is_err());
+
+        let Ok(Command::Run(_, options)) = parse(args("run --all -j 4 --timeout 2.5")) else {
+            panic!("expected a run command");
+        };
+        assert_eq!(options.jobs, Some(4));
+        assert_eq!(options.timeout, Some(Duration::from_millis(2500)));
+        assert!(parse(args("run --all --jobs 0")).is_err());
+        assert!(parse(args("run --all --timeout -1")).is_err());
+        assert!(parse(args("verify --cross-check")).is_err());
+    }
+
+    #[test]
+    fn test_parse_bench() {
+        assert_eq!(
+            parse(args(
+                "bench --all -n 50 --compare base.json --threshold 2.5"
+            )),
+            Ok(Command::Bench(BenchArgs {
+                run: RunArgs {
+                    days: Selection::All,
+                    parts: vec![Part::One, Part::Two],
+                    input: InputSource::Default,
+                },
+                iterations: 50,
+                save: None,
+                compare: Some(PathBuf::from("base.json")),
+                threshold: 2.5,
+            }))
+        );
+        assert!(parse(args("bench 2 --iterations 0")).is_err());
+        assert!(parse(args("bench 2 --threshold NaN")).is_err());
+        assert!(parse(args("bench 2 --threshold inf")).is_err());
+        assert!(parse(args("run 2 --iterations 5")).is_err());
+    }
+
+    #[test]
+    fn test_parse_verify() {
+        assert_eq!(
+            parse(args("verify --record")),
+            Ok(Command::Verify(VerifyArgs {
+                run: RunArgs {
+                    days: Selection::All,
+                    parts: vec![Part::One, Part::Two],
+                    input: InputSource::Default,
+                },
+                answers: None,
+                record: true,
+            }))
+        );
+        assert_eq!(
+            parse(args("verify 4 --answers known.toml")),
+            Ok(Command::Verify(VerifyArgs {
+                run: RunArgs {
+                    days: Selection::Day(4),
+                    parts: vec![Part::One, Part::Two],
+                    input: InputSource::Default,
+                },
+                answers: Some(PathBuf::from("known.toml")),
+                record: false,
+            }))
+        );
+    }
+
+    #[test]
+    fn test_parse_fuzz() {
+        assert_eq!(
+            parse(args("fuzz 6 -n 500 --seed 42")),
+            Ok(Command::Fuzz(FuzzArgs {
+                days: Selection::Day(6),
+                iterations: 500,
+                seed: Some(42),
+            }))
+        );
+        assert!(parse(args("fuzz")).is_err());
+        assert!(parse(args("fuzz --all --part 1")).is_err());
+    }
+
+    #[test]
+    fn test_parse_render() {
+        assert_eq!(
+            parse(args("render 4 --ppm frames --scale 8")),
+            Ok(Command::Render(RenderArgs {
+                day: 4,
+                input: InputSource::Default,
+                ppm: Some(PathBuf::from("frames")),
+                scale: 8,
+                delay: Duration::from_millis(100),
+            }))
+        );
+        let Ok(Command::Render(render)) = parse(args("render 7 -i - --delay 20")) else {
+            panic!("expected a render command");
+        };
+        assert_eq!(render.input, InputSource::Stdin);
+        assert_eq!(render.delay, Duration::from_millis(20));
+        assert!(parse(args("render --all")).is_err());
+        assert!(parse(args("render 4 --scale 0")).is_err());
+    }
+
+    #[test]
+    fn test_parse_new() {
+        assert_eq!(parse(args("new 13")), Ok(Command::New(13)));
+        assert!(parse(args("new")).is_err());
+        assert!(parse(args("new 26")).is_err());
+        assert!(parse(args("new 13 14")).is_err());
+    }
+
+    #[test]
+    fn test_parse_serve() {
+        assert_eq!(
+            parse(args("serve")),
+            Ok(Command::Serve(ServeArgs {
+                port: 8080,
+                connections: 16,
+                timeout: Duration::from_secs(10),
+            }))
+        );
+        assert_eq!(
+            parse(args("serve --port 3000 --connections 2 --timeout 0.5")),
+            Ok(Command::Serve(ServeArgs {
+                port: 3000,
+                connections: 2,
+                timeout: Duration::from_millis(500),
+            }))
+        );
+        assert!(parse(args("serve --port 70000")).is_err());
+        assert!(parse(args("serve --connections 0")).is_err());
+        assert!(parse(args("serve --timeout 0")).is_err());
+        assert!(parse(args("serve 3000")).is_err());
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(parse(args("run")).is_err());
+        assert!(parse(args("run 7 --all")).is_err());
+        assert!(parse(args("run 0")).is_err());
+        assert!(parse(args("run 3 --part 3")).is_err());
+        assert!(parse(args("fly 3")).is_err());
+        assert!(parse(args("run --all --input day01.txt")).is_err());
+    }
+}
@@ -0,0 +1,335 @@
+use std::{
+    cell::Cell,
+    fs, io,
+    panic::{self, AssertUnwindSafe},
+    path::{Path, PathBuf},
+    sync::Once,
+};
+
+use aoc_core::Puzzle;
+use rand::{Rng, SeedableRng, rngs::StdRng, seq::IndexedRandom};
+
+use crate::panic_message;
+
+/// Accepted inputs kept for further mutation, on top of the seed corpus.
+const MAX_CORPUS: usize = 256;
+
+/// Tokens that tend to trip up parsers: delimiters of the puzzle formats, numbers at the
+/// edges of the integer types, odd whitespace and multi-byte characters.
+const TOKENS: &[&str] = &[
+    "",
+    "-",
+    "0",
+    "-1",
+    "00",
+    "4294967296",
+    "18446744073709551615",
+    "99999999999999999999999",
+    ":",
+    ": ",
+    ",",
+    ",,",
+    "x",
+    "0x0",
+    " ",
+    "  ",
+    "\n",
+    "\n\n",
+    "\r\n",
+    "\t",
+    "[",
+    "]",
+    "(",
+    ")",
+    "{",
+    "}",
+    "#",
+    ".",
+    "@",
+    "^",
+    "S",
+    "+",
+    "*",
+    "L",
+    "R",
+    "é",
+    "🎄",
+];
+
+/// Where the seed corpus of `day` under `root` comes from: the puzzle examples in
+/// `dayNN/examples/`, and any extra seeds in `dayNN/fuzz/corpus/`.
+pub fn corpus_dirs(root: &Path, day: u8) -> [PathBuf; 2] {
+    let dir = root.join(format!("day{:02}", day));
+    [dir.join("examples"), dir.join("fuzz").join("corpus")]
+}
+
+pub fn crash_dir(root: &Path, day: u8) -> PathBuf {
+    root.join(format!("day{:02}", day))
+        .join("fuzz")
+        .join("crashes")
+}
+
+/// Reads every `.txt` file in `dirs`, in name order within each directory. Directories
+/// that do not exist are skipped.
+pub fn load_corpus(dirs: &[PathBuf]) -> io::Result<Vec<String>> {
+    let mut corpus = Vec::new();
+    for dir in dirs.iter().filter(|dir| dir.exists()) {
+        let mut paths: Vec<PathBuf> = fs::read_dir(dir)?
+            .map(|entry| entry.map(|entry| entry.path()))
+            .collect::<Result<_, _>>()?;
+        paths.retain(|path| path.extension().is_some_and(|ext| ext == "txt"));
+        paths.sort();
+        for path in paths {
+            corpus.push(fs::read_to_string(path)?);
+        }
+    }
+    Ok(corpus)
+}
+
+/// An input on which the parser panicked.
+#[derive(Debug)]
+pub struct Crash {
+    pub input: String,
+    pub message: String,
+}
+
+#[derive(Debug, Default, PartialEq)]
+pub struct Stats {
+    pub runs: usize,
+    /// Inputs the parser rejected with a parse error.
+    pub rejected: usize,
+}
+
+/// Feeds `iterations` mutations of `corpus` to the parser of `puzzle`, and stops at the
+/// first input that makes it, or the rendering of its parse error, panic.
+pub fn fuzz(
+    puzzle: &Puzzle,
+    mut corpus: Vec<String>,
+    iterations: usize,
+    seed: u64,
+) -> Result<Stats, Crash> {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut stats = Stats::default();
+    let seeds = corpus.len();
+
+    let result = quietly(|| {
+        for input in corpus.clone() {
+            check(puzzle, input, &mut stats)?;
+        }
+        for _ in 0..iterations {
+            let input = mutate(&mut rng, &corpus);
+            if check(puzzle, input.clone(), &mut stats)? && corpus.len() < seeds + MAX_CORPUS {
+                corpus.push(input);
+            }
+        }
+        Ok(())
+    });
+
+    result.map(|()| stats)
+}
+
+thread_local! {
+    /// Whether panics on this thread are expected, and so not printed.
+    static QUIET: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Runs `f` without printing the panics it catches. The panic hook is process-wide, so it
+/// is installed once and stays: it keeps quiet on threads inside `quietly` and hands every
+/// other panic to the hook it replaced.
+fn quietly<T>(f: impl FnOnce() -> T) -> T {
+    /// Restores the previous flag even if `f` panics.
+    struct Restore(bool);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            QUIET.set(self.0);
+        }
+    }
+
+    static INSTALL: Once = Once::new();
+    INSTALL.call_once(|| {
+        let previous = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if !QUIET.get() {
+                previous(info);
+            }
+        }));
+    });
+
+    let _restore = Restore(QUIET.replace(true));
+    f()
+}
+
+/// Whether the parser accepted `input`.
+fn check(puzzle: &Puzzle, input: String, stats: &mut Stats) -> Result<bool, Crash> {
+    stats.runs += 1;
+    let outcome = panic::catch_unwind(AssertUnwindSafe(|| match puzzle.parse(&input) {
+        Ok(()) => true,
+        Err(e) => {
+            e.render(&input);
+            false
+        }
+    }));
+
+    match outcome {
+        Ok(accepted) => {
+            if !accepted {
+                stats.rejected += 1;
+            }
+            Ok(accepted)
+        }
+        Err(payload) => Err(Crash {
+            message: panic_message(payload.as_ref()).to_string(),
+            input,
+        }),
+    }
+}
+
+/// A random corpus entry with one to four random edits.
+pub fn mutate(rng: &mut impl Rng, corpus: &[String]) -> String {
+    let mut bytes = corpus.choose(rng).cloned().unwrap_or_default().into_bytes();
+    for _ in 0..rng.random_range(1..=4) {
+        edit(rng, &mut bytes, corpus);
+    }
+    String::from_utf8_lossy(&bytes).into_owned()
+}
+
+fn edit(rng: &mut impl Rng, bytes: &mut Vec<u8>, corpus: &[String]) {
+    let len = bytes.len();
+    let at = rng.random_range(0..=len);
+    match rng.random_range(0..8) {
+        0 if len > 0 => bytes[at.min(len - 1)] = rng.random(),
+        1 => {
+            let token = TOKENS.choose(rng).unwrap();
+            bytes.splice(at..at, token.bytes());
+        }
+        2 => {
+            let end = rng.random_range(at..=len);
+            bytes.drain(at..end);
+        }
+        3 => {
+            let end = rng.random_range(at..=len);
+            let copy = bytes[at..end].to_vec();
+            bytes.splice(at..at, copy);
+        }
+        4 => bytes.truncate(at),
+        5 => {
+            let mut lines: Vec<Vec<u8>> =
+                bytes.split(|&b| b == b'\n').map(<[u8]>::to_vec).collect();
+            let (i, j) = (
+                rng.random_range(0..lines.len()),
+                rng.random_range(0..lines.len()),
+            );
+            match rng.random_range(0..3) {
+                0 => lines.swap(i, j),
+                1 => drop(lines.remove(i)),
+                _ => lines.insert(i, lines[j].clone()),
+            }
+            *bytes = lines.join(&b'\n');
+        }
+        6 => {
+            // Replace a run of digits with another number.
+            if let Some(start) = (at..len).find(|&i| bytes[i].is_ascii_digit()) {
+                let end = (start..len)
+                    .find(|&i| !bytes[i].is_ascii_digit())
+                    .unwrap_or(len);
+                let number = match rng.random_range(0..3) {
+                    0 => rng.random_range(0..10u64).to_string(),
+                    1 => rng.random::<u64>().to_string(),
+                    _ => "9".repeat(rng.random_range(1..40)),
+                };
+                bytes.splice(start..end, number.bytes());
+            }
+        }
+        _ => {
+            // Splice the tail of another corpus entry onto this one.
+            let other = corpus.choose(rng).map(String::as_bytes).unwrap_or_default();
+            let from = rng.random_range(0..=other.len());
+            bytes.truncate(at);
+            bytes.extend_from_slice(&other[from..]);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::{ParseError, Solution};
+
+    use super::*;
+
+    struct Fragile;
+
+    impl Solution for Fragile {
+        const DAY: u8 = 1;
+
+        type Input = u64;
+        type Answer1 = u64;
+        type Answer2 = u64;
+
+        /// Panics on input that is not a line of digits, which mutations find quickly.
+        fn parse(input: &str) -> Result<Self::Input, ParseError> {
+            Ok(input.trim_end().parse().unwrap())
+        }
+
+        fn part1(input: &Self::Input) -> Self::Answer1 {
+            *input
+        }
+
+        fn part2(input: &Self::Input) -> Self::Answer2 {
+            *input
+        }
+    }
+
+    #[test]
+    fn test_mutate_is_reproducible() {
+        let corpus = vec!["L68\nR4\n".to_string(), "1-2,3-4".to_string()];
+        let run = |seed| {
+            let mut rng = StdRng::seed_from_u64(seed);
+            (0..100)
+                .map(|_| mutate(&mut rng, &corpus))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(run(7), run(7));
+        assert!(run(7).iter().any(|input| !corpus.contains(input)));
+    }
+
+    #[test]
+    fn test_fuzz_finds_panics() {
+        let puzzle = Puzzle::of::<Fragile>();
+        let crash = fuzz(&puzzle, vec!["1234\n".to_string()], 1000, 1).unwrap_err();
+        assert!(crash.input.trim_end().parse::<u64>().is_err());
+        assert!(crash.message.contains("ParseIntError"));
+    }
+
+    #[test]
+    fn test_quietly_is_per_thread() {
+        assert!(!QUIET.get());
+        let caught = quietly(|| {
+            assert!(QUIET.get());
+            // Another thread's panics are not this fuzz run's to hide.
+            std::thread::spawn(|| QUIET.get()).join().unwrap()
+        });
+        assert!(!caught);
+        assert!(!QUIET.get());
+
+        let panicked = panic::catch_unwind(|| quietly(|| panic!("expected")));
+        assert!(panicked.is_err());
+        assert!(!QUIET.get());
+    }
+
+    #[test]
+    fn test_day_parsers_do_not_panic() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+        for puzzle in aoc_days::DAYS {
+            let corpus = load_corpus(&corpus_dirs(&root, puzzle.day)).unwrap();
+            assert!(!corpus.is_empty(), "day {} has no fuzz corpus", puzzle.day);
+
+            if let Err(crash) = fuzz(puzzle, corpus, 2000, u64::from(puzzle.day)) {
+                panic!(
+                    "day {} parser panicked ({}) on {:?}",
+                    puzzle.day, crash.message, crash.input
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,545 @@
+use std::{
+    any::Any,
+    env, fs, io,
+    net::TcpListener,
+    panic,
+    path::Path,
+    process::ExitCode,
+    sync::Arc,
+    thread,
+    time::{Instant, SystemTime, UNIX_EPOCH},
+};
+
+use aoc_core::{
+    Format, InputSource, Json, Part, Puzzle, Report, StrategyChoice, cancel, output, trace,
+};
+
+use answers::Answers;
+use bench::Measurement;
+use cli::{
+    BenchArgs, Command, FuzzArgs, RenderArgs, RunArgs, RunOptions, Selection, ServeArgs, VerifyArgs,
+};
+use pool::Status;
+use summary::Outcome;
+
+mod answers;
+mod bench;
+mod cli;
+mod fuzz;
+mod pool;
+mod scaffold;
+mod serve;
+mod summary;
+mod table;
+
+fn main() -> ExitCode {
+    let command = match cli::parse(env::args().skip(1)) {
+        Ok(command) => command,
+        Err(e) => {
+            eprintln!("Error: {}\n\n{}", e, cli::USAGE);
+            return ExitCode::from(2);
+        }
+    };
+
+    match command {
+        Command::Run(args, options) => run(args, options),
+        Command::Bench(args) => bench(args),
+        Command::Verify(args) => verify(args),
+        Command::Fuzz(args) => run_fuzz(args),
+        Command::Render(args) => render(args),
+        Command::New(day) => new_day(day),
+        Command::Serve(args) => serve(args),
+        Command::Help => {
+            println!("{}", cli::USAGE);
+            ExitCode::SUCCESS
+        }
+    }
+}
+
+fn select(days: &Selection) -> Option<Vec<&'static Puzzle>> {
+    match *days {
+        Selection::All => Some(aoc_days::DAYS.iter().collect()),
+        Selection::Day(day) => match aoc_days::find(day) {
+            Some(puzzle) => Some(vec![puzzle]),
+            None => {
+                eprintln!("Error: day {} is not implemented", day);
+                None
+            }
+        },
+    }
+}
+
+fn run(args: RunArgs, options: RunOptions) -> ExitCode {
+    let Some(puzzles) = select(&args.days) else {
+        return ExitCode::FAILURE;
+    };
+
+    if let Some(name) = &options.strategy {
+        let checks: Vec<_> = puzzles
+            .iter()
+            .map(|puzzle| puzzle.check_strategy(name, &args.parts))
+            .collect();
+        if !checks.iter().any(Result::is_ok) {
+            for e in checks.into_iter().filter_map(Result::err) {
+                eprintln!("Error: {}", e);
+            }
+            return ExitCode::FAILURE;
+        }
+    }
+
+    let start = Instant::now();
+    let outcomes = run_parallel(&puzzles, &args, &options);
+    let wall = start.elapsed();
+
+    let format = options.format;
+    match format {
+        Format::Text => {
+            summary::print_table(&outcomes, options.choice() != StrategyChoice::Default, wall)
+        }
+        Format::Json => println!("{}", output::to_json(&solved(&outcomes))),
+        Format::Csv => print!("{}", output::to_csv(&solved(&outcomes))),
+    }
+    if format == Format::Text {
+        summary::print_traces(&outcomes);
+        summary::print_failure_details(&outcomes);
+    } else {
+        summary::print_failures(&outcomes);
+    }
+
+    let mut disagreed = false;
+    for report in solved(&outcomes) {
+        for part in report.disagreements() {
+            eprintln!("Error: {}", aoc_core::describe_disagreement(&report, part));
+            disagreed = true;
+        }
+    }
+
+    if disagreed || outcomes.iter().any(Outcome::is_failure) {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn solved(outcomes: &[Outcome]) -> Vec<Report> {
+    outcomes
+        .iter()
+        .filter_map(|outcome| match outcome {
+            Outcome::Solved(report) => Some(report.clone()),
+            Outcome::Failed { .. } | Outcome::TimedOut { .. } => None,
+        })
+        .collect()
+}
+
+fn verify(args: VerifyArgs) -> ExitCode {
+    let Some(puzzles) = select(&args.run.days) else {
+        return ExitCode::FAILURE;
+    };
+
+    let path = args.answers.unwrap_or_else(answers::default_path);
+    let mut known = match Answers::load(&path) {
+        Ok(known) => known,
+        Err(e) => {
+            eprintln!("Error: cannot load answers {}: {}", path.display(), e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let outcomes: Vec<Outcome> = puzzles
+        .into_iter()
+        .map(|puzzle| {
+            execute(
+                puzzle,
+                &args.run.parts,
+                &args.run.input,
+                StrategyChoice::Default,
+            )
+        })
+        .collect();
+    let checks = answers::verify(&outcomes, &mut known, args.record);
+
+    answers::print_table(&checks, &outcomes);
+    summary::print_failure_details(&outcomes);
+
+    let mut failed = outcomes.iter().any(Outcome::is_failure)
+        || checks
+            .iter()
+            .any(|check| matches!(check.status, answers::Status::Fail { .. }));
+
+    if checks
+        .iter()
+        .any(|check| check.status == answers::Status::Recorded)
+    {
+        match fs::write(&path, known.to_string()) {
+            Ok(()) => println!("Recorded new answers in {}", path.display()),
+            Err(e) => {
+                eprintln!("Error: cannot write {}: {}", path.display(), e);
+                failed = true;
+            }
+        }
+    }
+
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+/// Solves each selected part of each day as its own task on the thread pool, in day and
+/// part order. Inputs are read up front, so that stdin is only read once.
+fn run_parallel(puzzles: &[&'static Puzzle], args: &RunArgs, options: &RunOptions) -> Vec<Outcome> {
+    let mut outcomes = Vec::new();
+    let mut tasks: Vec<pool::Task<Outcome>> = Vec::new();
+    // Where each task's outcome goes in `outcomes`.
+    let mut slots = Vec::new();
+
+    for &puzzle in puzzles {
+        let input: Arc<str> = match read_input(puzzle, &args.input) {
+            Ok(input) => input.into(),
+            Err(outcome) => {
+                outcomes.push(Some(outcome));
+                continue;
+            }
+        };
+        for &part in &args.parts {
+            let input = Arc::clone(&input);
+            let strategy = options.strategy.clone();
+            let (cross_check, explain) = (options.cross_check, options.explain);
+            tasks.push(Box::new(move |token| {
+                let choice = StrategyChoice::from_flags(strategy.as_deref(), cross_check);
+                // Traces and tokens are per thread, so both are set up on the worker.
+                let solve = || solve(puzzle, &input, &[part], choice);
+                cancel::with(token, || match explain {
+                    Some(verbosity) => trace::explain(verbosity, solve),
+                    None => solve(),
+                })
+            }));
+            slots.push((outcomes.len(), puzzle.day, part));
+            outcomes.push(None);
+        }
+    }
+
+    let jobs = options
+        .jobs
+        .unwrap_or_else(|| thread::available_parallelism().map_or(1, usize::from));
+    let statuses = pool::run(tasks, jobs, options.timeout);
+    for ((slot, day, part), status) in slots.into_iter().zip(statuses) {
+        outcomes[slot] = Some(match status {
+            Status::Done(outcome) => outcome,
+            Status::Panicked(message) => Outcome::Failed {
+                day,
+                part: Some(part),
+                reason: format!("panicked: {}", message),
+                details: None,
+            },
+            Status::TimedOut(limit) => Outcome::TimedOut {
+                day,
+                part: Some(part),
+                limit,
+            },
+        });
+    }
+
+    let mut outcomes: Vec<Outcome> = outcomes.into_iter().map(Option::unwrap).collect();
+    // Each part parses the input, so a parse error shows up once per part.
+    outcomes.dedup_by(|a, b| match (a, b) {
+        (
+            Outcome::Failed {
+                day,
+                part: None,
+                reason,
+                ..
+            },
+            Outcome::Failed {
+                day: other_day,
+                part: None,
+                reason: other_reason,
+                ..
+            },
+        ) => day == other_day && reason == other_reason,
+        _ => false,
+    });
+    outcomes
+}
+
+fn execute(
+    puzzle: &Puzzle,
+    parts: &[Part],
+    source: &InputSource,
+    choice: StrategyChoice,
+) -> Outcome {
+    match read_input(puzzle, source) {
+        Ok(input) => solve(puzzle, &input, parts, choice),
+        Err(outcome) => outcome,
+    }
+}
+
+fn read_input(puzzle: &Puzzle, source: &InputSource) -> Result<String, Outcome> {
+    source.read(puzzle.day).map_err(|e| Outcome::Failed {
+        day: puzzle.day,
+        part: None,
+        reason: e.to_string(),
+        details: None,
+    })
+}
+
+/// Solves `parts`, reporting parse errors as a failure of the whole day and panics as a
+/// failure of the parts.
+fn solve(puzzle: &Puzzle, input: &str, parts: &[Part], choice: StrategyChoice) -> Outcome {
+    let part = match parts {
+        &[part] => Some(part),
+        _ => None,
+    };
+    match panic::catch_unwind(|| puzzle.run_with(input, parts, choice)) {
+        Ok(Ok(report)) => Outcome::Solved(report),
+        Ok(Err(e)) => Outcome::Failed {
+            day: puzzle.day,
+            part: None,
+            reason: format!("parse error at line {}, column {}", e.line, e.column),
+            details: Some(e.render(input)),
+        },
+        Err(payload) => Outcome::Failed {
+            day: puzzle.day,
+            part,
+            reason: format!("panicked: {}", panic_message(payload.as_ref())),
+            details: None,
+        },
+    }
+}
+
+fn bench(args: BenchArgs) -> ExitCode {
+    let Some(puzzles) = select(&args.run.days) else {
+        return ExitCode::FAILURE;
+    };
+
+    let baseline = match &args.compare {
+        Some(path) => match load_baseline(path) {
+            Ok(baseline) => Some(baseline),
+            Err(e) => {
+                eprintln!("Error: cannot load baseline {}: {}", path.display(), e);
+                return ExitCode::FAILURE;
+            }
+        },
+        None => None,
+    };
+
+    let mut measurements = Vec::new();
+    let mut failed = false;
+    for puzzle in puzzles {
+        let input = match args.run.input.read(puzzle.day) {
+            Ok(input) => input,
+            Err(e) => {
+                eprintln!("Day {}: {}", puzzle.day, e);
+                failed = true;
+                continue;
+            }
+        };
+
+        match panic::catch_unwind(|| {
+            bench::measure(puzzle, &input, &args.run.parts, args.iterations)
+        }) {
+            Ok(Ok(results)) => measurements.extend(results),
+            Ok(Err(e)) => {
+                eprintln!("Day {}: {}", puzzle.day, e.render(&input));
+                failed = true;
+            }
+            Err(payload) => {
+                eprintln!(
+                    "Day {}: panicked: {}",
+                    puzzle.day,
+                    panic_message(payload.as_ref())
+                );
+                failed = true;
+            }
+        }
+    }
+
+    bench::print_table(&measurements, baseline.as_deref(), args.threshold);
+
+    if let Some(path) = &args.save {
+        let json = bench::to_json(&measurements).to_string() + "\n";
+        if let Err(e) = fs::write(path, json) {
+            eprintln!("Error: cannot write {}: {}", path.display(), e);
+            failed = true;
+        }
+    }
+
+    let regressed = baseline.is_some_and(|baseline| {
+        measurements
+            .iter()
+            .any(|m| bench::compare(m, &baseline, args.threshold).is_some_and(|c| c.regressed))
+    });
+    if regressed {
+        eprintln!(
+            "Error: median time regressed by more than {}% against the baseline",
+            args.threshold
+        );
+    }
+
+    if failed || regressed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn run_fuzz(args: FuzzArgs) -> ExitCode {
+    let Some(puzzles) = select(&args.days) else {
+        return ExitCode::FAILURE;
+    };
+
+    let seed = args.seed.unwrap_or_else(|| {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |time| time.as_nanos() as u64)
+    });
+    println!("Fuzzing with --seed {}", seed);
+
+    let root = Path::new(".");
+    let mut failed = false;
+    for puzzle in puzzles {
+        let dirs = fuzz::corpus_dirs(root, puzzle.day);
+        let corpus = match fuzz::load_corpus(&dirs) {
+            Ok(corpus) if !corpus.is_empty() => corpus,
+            Ok(_) => {
+                eprintln!(
+                    "Day {}: no .txt files in {} or {}",
+                    puzzle.day,
+                    dirs[0].display(),
+                    dirs[1].display()
+                );
+                failed = true;
+                continue;
+            }
+            Err(e) => {
+                eprintln!("Day {}: cannot read the corpus: {}", puzzle.day, e);
+                failed = true;
+                continue;
+            }
+        };
+
+        match fuzz::fuzz(puzzle, corpus, args.iterations, seed) {
+            Ok(stats) => println!(
+                "Day {}: {} inputs, {} rejected, no panics",
+                puzzle.day, stats.runs, stats.rejected
+            ),
+            Err(crash) => {
+                failed = true;
+                let dir = fuzz::crash_dir(root, puzzle.day);
+                let path = dir.join(format!("crash-{}.txt", seed));
+                let saved = fs::create_dir_all(&dir).and_then(|()| fs::write(&path, &crash.input));
+                eprintln!("Day {}: parser panicked: {}", puzzle.day, crash.message);
+                match saved {
+                    Ok(()) => eprintln!("  input saved to {}", path.display()),
+                    Err(e) => eprintln!("  cannot save input to {}: {}", path.display(), e),
+                }
+            }
+        }
+    }
+
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn render(args: RenderArgs) -> ExitCode {
+    let Some(puzzles) = select(&Selection::Day(args.day)) else {
+        return ExitCode::FAILURE;
+    };
+    let puzzle = puzzles[0];
+
+    let input = match args.input.read(puzzle.day) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("Day {}: {}", puzzle.day, e);
+            return ExitCode::FAILURE;
+        }
+    };
+    let animation = match puzzle.animate(&input) {
+        Ok(Some(animation)) => animation,
+        Ok(None) => {
+            eprintln!("Error: day {} has no animation", puzzle.day);
+            return ExitCode::FAILURE;
+        }
+        Err(e) => {
+            eprintln!("Day {}: {}", puzzle.day, e.render(&input));
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let result = match &args.ppm {
+        Some(dir) => animation.write_ppm(dir, args.scale).map(|paths| {
+            println!("Wrote {} frames to {}", paths.len(), dir.display());
+        }),
+        None => animation.play(&mut io::stdout().lock(), args.delay),
+    };
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn new_day(day: u8) -> ExitCode {
+    let root = Path::new(".");
+    if aoc_days::find(day).is_some() {
+        eprintln!("Error: day {} already exists", day);
+        return ExitCode::FAILURE;
+    }
+    if !fs::read_to_string(root.join("Cargo.toml")).is_ok_and(|text| text.contains("[workspace]")) {
+        eprintln!("Error: run `aoc new` from the workspace root");
+        return ExitCode::FAILURE;
+    }
+
+    match scaffold::new_day(root, day) {
+        Ok(written) => {
+            for path in written {
+                println!("Wrote {}", path.display());
+            }
+            println!(
+                "Next: add the example and its answers to day{0:02}/examples/example.txt and \
+                 example.toml, and your input to day{0:02}/input.txt",
+                day
+            );
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn serve(args: ServeArgs) -> ExitCode {
+    let listener = match TcpListener::bind(("127.0.0.1", args.port)) {
+        Ok(listener) => listener,
+        Err(e) => {
+            eprintln!("Error: cannot listen on port {}: {}", args.port, e);
+            return ExitCode::FAILURE;
+        }
+    };
+    println!("Listening on http://127.0.0.1:{}", args.port);
+
+    serve::serve(listener, args.connections, args.timeout);
+    ExitCode::SUCCESS
+}
+
+fn load_baseline(path: &Path) -> Result<Vec<Measurement>, String> {
+    let text = fs::read_to_string(path).map_err(|e| e.to_string())?;
+    bench::from_json(&Json::parse(&text)?)
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> &str {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message
+    } else {
+        "unknown panic"
+    }
+}
@@ -0,0 +1,248 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    panic::{self, AssertUnwindSafe},
+    sync::{
+        Arc, Mutex,
+        mpsc::{self, RecvTimeoutError, Sender},
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use aoc_core::Cancel;
+
+use crate::panic_message;
+
+/// How a task ended.
+#[derive(Debug, PartialEq)]
+pub enum Status<T> {
+    Done(T),
+    Panicked(String),
+    /// Still running after the time limit. Its token is cancelled, and its thread left to
+    /// finish on its own.
+    TimedOut(Duration),
+}
+
+/// A task, given a token that fires if it times out.
+pub type Task<T> = Box<dyn FnOnce(Cancel) -> T + Send>;
+
+enum Message<T> {
+    Started(usize, Instant),
+    Finished(usize, thread::Result<T>),
+}
+
+struct Shared<T> {
+    queue: Mutex<VecDeque<(usize, Task<T>, Cancel)>>,
+    /// Tasks given up on, whose workers stop once the task returns, since a replacement
+    /// took their place.
+    abandoned: Mutex<Vec<bool>>,
+}
+
+/// Runs `tasks` on `jobs` threads and returns how each ended, in task order.
+///
+/// Threads cannot be killed, so a task that runs past `timeout` is reported as timed out,
+/// its token is cancelled so that it can stop early, and a new worker takes over its
+/// thread's share of the queue.
+pub fn run<T: Send + 'static>(
+    tasks: Vec<Task<T>>,
+    jobs: usize,
+    timeout: Option<Duration>,
+) -> Vec<Status<T>> {
+    let count = tasks.len();
+    let tokens: Vec<Cancel> = (0..count).map(|_| Cancel::new()).collect();
+    let queue = tasks
+        .into_iter()
+        .zip(&tokens)
+        .enumerate()
+        .map(|(index, (task, token))| (index, task, token.clone()))
+        .collect();
+    let shared = Arc::new(Shared {
+        queue: Mutex::new(queue),
+        abandoned: Mutex::new(vec![false; count]),
+    });
+    let (sender, receiver) = mpsc::channel();
+    for _ in 0..jobs.clamp(1, count.max(1)) {
+        spawn_worker(&shared, &sender);
+    }
+
+    let mut results: Vec<Option<Status<T>>> = (0..count).map(|_| None).collect();
+    let mut running: HashMap<usize, Instant> = HashMap::new();
+    let mut remaining = count;
+
+    while remaining > 0 {
+        // A limit too far off for an `Instant` is as good as none.
+        let deadline = timeout
+            .zip(running.values().min())
+            .and_then(|(limit, &start)| start.checked_add(limit));
+        let message = match deadline {
+            Some(deadline) => {
+                receiver.recv_timeout(deadline.saturating_duration_since(Instant::now()))
+            }
+            None => receiver.recv().map_err(|_| RecvTimeoutError::Disconnected),
+        };
+
+        match message {
+            Ok(Message::Started(index, start)) => {
+                running.insert(index, start);
+            }
+            Ok(Message::Finished(index, result)) => {
+                if results[index].is_none() {
+                    running.remove(&index);
+                    results[index] = Some(match result {
+                        Ok(value) => Status::Done(value),
+                        Err(payload) => {
+                            Status::Panicked(panic_message(payload.as_ref()).to_string())
+                        }
+                    });
+                    remaining -= 1;
+                }
+            }
+            Err(RecvTimeoutError::Timeout) => {
+                let limit = timeout.expect("only waits with a deadline when there is a timeout");
+                let now = Instant::now();
+                let expired: Vec<usize> = running
+                    .iter()
+                    .filter(|&(_, &start)| now - start >= limit)
+                    .map(|(&index, _)| index)
+                    .collect();
+                for index in expired {
+                    running.remove(&index);
+                    tokens[index].cancel();
+                    shared.abandoned.lock().unwrap()[index] = true;
+                    results[index] = Some(Status::TimedOut(limit));
+                    remaining -= 1;
+                    spawn_worker(&shared, &sender);
+                }
+            }
+            Err(RecvTimeoutError::Disconnected) => {
+                unreachable!("the pool keeps a sender for replacement workers")
+            }
+        }
+    }
+
+    results.into_iter().map(Option::unwrap).collect()
+}
+
+fn spawn_worker<T: Send + 'static>(shared: &Arc<Shared<T>>, sender: &Sender<Message<T>>) {
+    let shared = Arc::clone(shared);
+    let sender = sender.clone();
+    thread::spawn(move || {
+        loop {
+            let Some((index, task, token)) = shared.queue.lock().unwrap().pop_front() else {
+                return;
+            };
+            if sender
+                .send(Message::Started(index, Instant::now()))
+                .is_err()
+            {
+                return;
+            }
+            let result = panic::catch_unwind(AssertUnwindSafe(|| task(token)));
+            let abandoned = shared.abandoned.lock().unwrap()[index];
+            if sender.send(Message::Finished(index, result)).is_err() || abandoned {
+                return;
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+
+    fn task<T>(f: impl FnOnce() -> T + Send + 'static) -> Task<T> {
+        Box::new(|_| f())
+    }
+
+    #[test]
+    fn test_results_in_task_order() {
+        let tasks = (0..20u64)
+            .map(|i| {
+                task(move || {
+                    thread::sleep(Duration::from_millis(20 - i));
+                    i * i
+                })
+            })
+            .collect();
+        let expected: Vec<_> = (0..20u64).map(|i| Status::Done(i * i)).collect();
+        assert_eq!(run(tasks, 4, None), expected);
+    }
+
+    #[test]
+    fn test_runs_at_most_jobs_tasks_at_once() {
+        let active = Arc::new(AtomicUsize::new(0));
+        let peak = Arc::new(AtomicUsize::new(0));
+        let tasks = (0..12)
+            .map(|_| {
+                let (active, peak) = (Arc::clone(&active), Arc::clone(&peak));
+                task(move || {
+                    let now = active.fetch_add(1, Ordering::SeqCst) + 1;
+                    peak.fetch_max(now, Ordering::SeqCst);
+                    thread::sleep(Duration::from_millis(10));
+                    active.fetch_sub(1, Ordering::SeqCst);
+                })
+            })
+            .collect();
+        run(tasks, 3, None);
+        assert!((2..=3).contains(&peak.load(Ordering::SeqCst)));
+    }
+
+    #[test]
+    fn test_timeouts_and_panics_do_not_stop_other_tasks() {
+        let limit = Duration::from_millis(50);
+        let tasks = vec![
+            task(|| {
+                thread::sleep(Duration::from_secs(5));
+                1
+            }),
+            task(|| panic!("boom")),
+            task(|| 3),
+            task(|| 4),
+        ];
+
+        let start = Instant::now();
+        let results = run(tasks, 1, Some(limit));
+
+        assert!(start.elapsed() < Duration::from_secs(5));
+        assert_eq!(
+            results,
+            [
+                Status::TimedOut(limit),
+                Status::Panicked("boom".to_string()),
+                Status::Done(3),
+                Status::Done(4)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_timed_out_tasks_are_cancelled() {
+        let (sender, receiver) = mpsc::channel();
+        let tasks: Vec<Task<()>> = vec![Box::new(move |cancel| {
+            while !cancel.is_cancelled() {
+                thread::sleep(Duration::from_millis(1));
+            }
+            sender.send(()).unwrap();
+        })];
+        let limit = Duration::from_millis(20);
+        assert_eq!(run(tasks, 1, Some(limit)), [Status::TimedOut(limit)]);
+        assert_eq!(receiver.recv_timeout(Duration::from_secs(5)), Ok(()));
+    }
+
+    #[test]
+    fn test_huge_timeout() {
+        let timeout = aoc_core::cancel::parse_timeout("1e19").unwrap();
+        let tasks = vec![task(|| 1), task(|| 2)];
+        assert_eq!(
+            run(tasks, 1, Some(timeout)),
+            [Status::Done(1), Status::Done(2)]
+        );
+    }
+
+    #[test]
+    fn test_no_tasks() {
+        assert_eq!(run::<()>(Vec::new(), 4, None), []);
+    }
+}
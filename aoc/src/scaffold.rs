@@ -0,0 +1,237 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+const CARGO_TOML: &str = include_str!("../template/Cargo.toml.tmpl");
+const MAIN_RS: &str = include_str!("../template/main.rs.tmpl");
+const LIB_RS: &str = include_str!("../template/lib.rs.tmpl");
+const EXAMPLE_TOML: &str = include_str!("../template/example.toml.tmpl");
+
+/// Adds a day to the text of a file that lists the days.
+type Register = fn(&str, u8) -> Result<String, String>;
+
+/// Fills in `{{day}}` and the zero-padded `{{NN}}` of a template.
+fn render(template: &str, day: u8) -> String {
+    template
+        .replace("{{NN}}", &format!("{:02}", day))
+        .replace("{{day}}", &day.to_string())
+}
+
+/// Creates the `dayNN` crate under the workspace `root` and registers it in the workspace
+/// members and in `aoc-days`, the list of days the runner and the C library share.
+/// Returns the files it wrote.
+pub fn new_day(root: &Path, day: u8) -> Result<Vec<PathBuf>, String> {
+    let name = format!("day{:02}", day);
+    let dir = root.join(&name);
+    if dir.exists() {
+        return Err(format!("{} already exists", dir.display()));
+    }
+
+    // Check every registration before writing anything.
+    let registrations: [(PathBuf, Register); 3] = [
+        (root.join("Cargo.toml"), register_member),
+        (
+            root.join("aoc-days").join("Cargo.toml"),
+            register_dependency,
+        ),
+        (
+            root.join("aoc-days").join("src").join("lib.rs"),
+            register_puzzle,
+        ),
+    ];
+    let registrations = registrations
+        .into_iter()
+        .map(|(path, register)| {
+            let text = fs::read_to_string(&path)
+                .map_err(|e| format!("cannot read {}: {}", path.display(), e))?;
+            let text = register(&text, day).map_err(|e| format!("{}: {}", path.display(), e))?;
+            Ok((path, text))
+        })
+        .collect::<Result<Vec<_>, String>>()?;
+
+    let files = [
+        (dir.join("Cargo.toml"), render(CARGO_TOML, day)),
+        (dir.join("src").join("main.rs"), render(MAIN_RS, day)),
+        (dir.join("src").join("lib.rs"), render(LIB_RS, day)),
+        // Checked by the example tests and used as the fuzzer's seed once filled in.
+        (dir.join("examples").join("example.txt"), String::new()),
+        (
+            dir.join("examples").join("example.toml"),
+            render(EXAMPLE_TOML, day),
+        ),
+    ];
+
+    let mut written = Vec::new();
+    for (path, text) in files.into_iter().chain(registrations) {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|e| format!("cannot create {}: {}", parent.display(), e))?;
+        }
+        fs::write(&path, text).map_err(|e| format!("cannot write {}: {}", path.display(), e))?;
+        written.push(path);
+    }
+    Ok(written)
+}
+
+/// Adds `"dayNN"` to the workspace `members`.
+fn register_member(manifest: &str, day: u8) -> Result<String, String> {
+    insert_line(manifest, day, &format!("    \"day{:02}\",", day), |line| {
+        line.trim()
+            .strip_prefix("\"day")?
+            .strip_suffix("\",")?
+            .parse()
+            .ok()
+    })
+}
+
+/// Adds `dayNN = { path = "../dayNN" }` to a crate's dependencies.
+fn register_dependency(manifest: &str, day: u8) -> Result<String, String> {
+    insert_line(
+        manifest,
+        day,
+        &format!("day{0:02} = {{ path = \"../day{0:02}\" }}", day),
+        |line| line.strip_prefix("day")?.split_once(' ')?.0.parse().ok(),
+    )
+}
+
+/// Adds `Puzzle::of::<dayNN::DayNN>()` to the shared `DAYS`.
+fn register_puzzle(days: &str, day: u8) -> Result<String, String> {
+    insert_line(
+        days,
+        day,
+        &format!("    Puzzle::of::<day{0:02}::Day{0:02}>(),", day),
+        |line| {
+            line.trim()
+                .strip_prefix("Puzzle::of::<day")?
+                .split_once(':')?
+                .0
+                .parse()
+                .ok()
+        },
+    )
+}
+
+/// Inserts `new_line` among the lines that `day_of` recognises, keeping them in day order.
+fn insert_line(
+    text: &str,
+    day: u8,
+    new_line: &str,
+    day_of: impl Fn(&str) -> Option<u8>,
+) -> Result<String, String> {
+    let mut lines: Vec<&str> = text.lines().collect();
+    let days: Vec<(usize, u8)> = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, line)| Some((i, day_of(line)?)))
+        .collect();
+
+    if days.iter().any(|&(_, d)| d == day) {
+        return Err(format!("day {} is already registered", day));
+    }
+    let index = match days.iter().find(|&&(_, d)| d > day) {
+        Some(&(i, _)) => i,
+        None => days.last().ok_or("no days registered to add to")?.0 + 1,
+    };
+
+    lines.insert(index, new_line);
+    let mut out = lines.join("\n");
+    if text.ends_with('\n') {
+        out.push('\n');
+    }
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DAYS_LIB: &str = "use aoc_core::Puzzle;
+
+pub const DAYS: &[Puzzle] = &[
+    Puzzle::of::<day01::Day01>(),
+    Puzzle::of::<day03::Day03>(),
+];
+";
+
+    #[test]
+    fn test_register_member() {
+        let manifest = "[workspace]\nmembers = [\n    \"aoc\",\n    \"day01\",\n]\n";
+        assert_eq!(
+            register_member(manifest, 2),
+            Ok(
+                "[workspace]\nmembers = [\n    \"aoc\",\n    \"day01\",\n    \"day02\",\n]\n"
+                    .to_string()
+            )
+        );
+        assert!(register_member(manifest, 1).is_err());
+    }
+
+    #[test]
+    fn test_register_dependency() {
+        let manifest = "[dependencies]\naoc-core = { path = \"../aoc-core\" }\n\
+                        day01 = { path = \"../day01\" }\nrand = \"0.9.2\"\n";
+        assert_eq!(
+            register_dependency(manifest, 12),
+            Ok("[dependencies]\naoc-core = { path = \"../aoc-core\" }\n\
+                day01 = { path = \"../day01\" }\nday12 = { path = \"../day12\" }\n\
+                rand = \"0.9.2\"\n"
+                .to_string())
+        );
+    }
+
+    #[test]
+    fn test_register_puzzle_in_order() {
+        let days = register_puzzle(DAYS_LIB, 2).unwrap();
+        assert!(days.contains(
+            "    Puzzle::of::<day01::Day01>(),\n    \
+             Puzzle::of::<day02::Day02>(),\n    \
+             Puzzle::of::<day03::Day03>(),\n"
+        ));
+        assert!(register_puzzle(&days, 3).is_err());
+        assert!(register_puzzle("pub const DAYS: &[Puzzle] = &[];", 1).is_err());
+    }
+
+    #[test]
+    fn test_new_day() {
+        let root = std::env::temp_dir().join(format!("aoc-new-{}", std::process::id()));
+        fs::create_dir_all(root.join("aoc-days").join("src")).unwrap();
+        fs::write(
+            root.join("Cargo.toml"),
+            "[workspace]\nmembers = [\n    \"aoc\",\n    \"aoc-days\",\n    \"day01\",\n]\n",
+        )
+        .unwrap();
+        fs::write(
+            root.join("aoc-days").join("Cargo.toml"),
+            "[dependencies]\naoc-core = { path = \"../aoc-core\" }\nday01 = { path = \"../day01\" }\n",
+        )
+        .unwrap();
+        fs::write(root.join("aoc-days").join("src").join("lib.rs"), DAYS_LIB).unwrap();
+
+        let written = new_day(&root, 5).unwrap();
+        assert_eq!(written.len(), 8);
+        let lib = fs::read_to_string(root.join("day05").join("src").join("lib.rs")).unwrap();
+        assert!(lib.contains("pub struct Day05;"));
+        assert!(lib.contains("const DAY: u8 = 5;"));
+        assert!(!lib.contains("{{"));
+        let manifest = fs::read_to_string(root.join("day05").join("Cargo.toml")).unwrap();
+        assert!(manifest.contains("generate = [\"dep:rand\"]"));
+        assert!(manifest.contains("day05 = { path = \".\", features = [\"generate\"] }"));
+        let days = fs::read_to_string(root.join("aoc-days").join("src").join("lib.rs")).unwrap();
+        assert!(days.contains("Puzzle::of::<day05::Day05>()"));
+        let manifest = fs::read_to_string(root.join("aoc-days").join("Cargo.toml")).unwrap();
+        assert!(
+            manifest
+                .ends_with("day01 = { path = \"../day01\" }\nday05 = { path = \"../day05\" }\n")
+        );
+
+        assert!(new_day(&root, 5).unwrap_err().contains("already exists"));
+        assert!(
+            new_day(&root, 1)
+                .unwrap_err()
+                .contains("already registered")
+        );
+        assert!(!root.join("day01").exists());
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
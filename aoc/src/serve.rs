@@ -0,0 +1,512 @@
+use std::{
+    io::{self, BufRead, BufReader, Read, Write},
+    net::{TcpListener, TcpStream},
+    sync::{Arc, Condvar, Mutex},
+    thread,
+    time::{Duration, Instant},
+};
+
+use aoc_core::{Json, ParseError, Part, Puzzle, cancel, output};
+
+use crate::pool::{self, Status};
+
+/// Largest request body accepted, well above any puzzle input.
+const MAX_BODY: usize = 16 << 20;
+
+/// Largest request line plus headers accepted, far above what any client sends.
+const MAX_HEAD: u64 = 16 << 10;
+
+/// How long a client may take to send its request.
+const READ_TIMEOUT: Duration = Duration::from_secs(30);
+
+#[derive(Debug, PartialEq)]
+pub struct Response {
+    pub status: u16,
+    pub body: Json,
+}
+
+impl Response {
+    fn ok(body: Json) -> Self {
+        Response { status: 200, body }
+    }
+
+    fn error(status: u16, message: impl Into<String>) -> Self {
+        Response {
+            status,
+            body: Json::object([("error", Json::from(message.into()))]),
+        }
+    }
+
+    fn parse_error(error: &ParseError, input: &str) -> Self {
+        Response {
+            status: 400,
+            body: Json::object([
+                ("error", Json::from("parse error")),
+                ("message", Json::from(error.render(input))),
+                ("line", Json::from(error.line)),
+                ("column", Json::from(error.column)),
+                ("token", Json::from(error.token.as_str())),
+                ("expected", Json::from(error.expected.as_str())),
+            ]),
+        }
+    }
+
+    fn reason(&self) -> &'static str {
+        match self.status {
+            200 => "OK",
+            400 => "Bad Request",
+            404 => "Not Found",
+            405 => "Method Not Allowed",
+            411 => "Length Required",
+            413 => "Content Too Large",
+            431 => "Request Header Fields Too Large",
+            503 => "Service Unavailable",
+            _ => "Internal Server Error",
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Request {
+    pub method: String,
+    pub path: String,
+    pub body: Vec<u8>,
+}
+
+/// Answers requests on `listener` forever, each connection on its own thread and at most
+/// `connections` at once; the rest wait to be accepted. A failed accept only loses that
+/// connection. Solves still running after `timeout` are answered as timed out.
+pub fn serve(listener: TcpListener, connections: usize, timeout: Duration) {
+    let slots = Arc::new(Slots {
+        free: Mutex::new(connections),
+        freed: Condvar::new(),
+    });
+    loop {
+        let slot = Slots::take(&slots);
+        let stream = match listener.accept() {
+            Ok((stream, _)) => stream,
+            Err(e) => {
+                eprintln!("Error: cannot accept a connection: {}", e);
+                continue;
+            }
+        };
+        thread::spawn(move || {
+            let _slot = slot;
+            if let Err(e) = handle(stream, timeout) {
+                eprintln!("Error: {}", e);
+            }
+        });
+    }
+}
+
+/// Connections that may still be handled at once.
+struct Slots {
+    free: Mutex<usize>,
+    freed: Condvar,
+}
+
+/// A connection's place among `Slots`, given back when dropped.
+struct Slot(Arc<Slots>);
+
+impl Slots {
+    /// Waits for a free slot and takes it.
+    fn take(slots: &Arc<Slots>) -> Slot {
+        let mut free = slots.free.lock().unwrap();
+        while *free == 0 {
+            free = slots.freed.wait(free).unwrap();
+        }
+        *free -= 1;
+        Slot(Arc::clone(slots))
+    }
+}
+
+impl Drop for Slot {
+    fn drop(&mut self) {
+        *self.0.free.lock().unwrap() += 1;
+        self.0.freed.notify_one();
+    }
+}
+
+/// Reads one request from `stream`, answers it and closes the connection.
+fn handle(mut stream: TcpStream, timeout: Duration) -> io::Result<()> {
+    stream.set_read_timeout(Some(READ_TIMEOUT))?;
+    let start = Instant::now();
+    let (line, response) = match read_request(&mut BufReader::new(&stream)) {
+        Ok(request) => (
+            format!("{} {}", request.method, request.path),
+            respond(&request, timeout),
+        ),
+        Err(response) => ("malformed request".to_string(), response),
+    };
+    println!("{} -> {} ({:.1?})", line, response.status, start.elapsed());
+
+    let body = response.body.to_string() + "\n";
+    let mut head = format!(
+        "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\n\
+         Connection: close\r\n",
+        response.status,
+        response.reason(),
+        body.len()
+    );
+    if response.status == 405 {
+        head.push_str("Allow: POST\r\n");
+    }
+    head.push_str("\r\n");
+    stream.write_all(head.as_bytes())?;
+    stream.write_all(body.as_bytes())?;
+    stream.flush()
+}
+
+/// Reads the request line, the headers and a body of `Content-Length` bytes, or the error
+/// response for a request that cannot be read.
+pub fn read_request(reader: &mut impl BufRead) -> Result<Request, Response> {
+    let malformed = |_| Response::error(400, "malformed request");
+    let mut head_left = MAX_HEAD;
+    let mut line = String::new();
+    read_head_line(reader, &mut line, &mut head_left)?;
+    let mut words = line.split_whitespace();
+    let (Some(method), Some(target), Some(_version)) = (words.next(), words.next(), words.next())
+    else {
+        return Err(Response::error(400, "malformed request line"));
+    };
+    let (method, target) = (method.to_string(), target.to_string());
+
+    let mut length = None;
+    loop {
+        line.clear();
+        if read_head_line(reader, &mut line, &mut head_left)? == 0 {
+            return Err(Response::error(400, "request ended inside the headers"));
+        }
+        let header = line.trim_end();
+        if header.is_empty() {
+            break;
+        }
+        let Some((name, value)) = header.split_once(':') else {
+            return Err(Response::error(
+                400,
+                format!("malformed header `{}`", header),
+            ));
+        };
+        if name.trim().eq_ignore_ascii_case("content-length") {
+            let value = value.trim();
+            length = Some(value.parse::<usize>().map_err(|_| {
+                Response::error(400, format!("invalid Content-Length `{}`", value))
+            })?);
+        }
+    }
+
+    let body = match length {
+        Some(length) if length > MAX_BODY => {
+            return Err(Response::error(
+                413,
+                format!("input is larger than {} bytes", MAX_BODY),
+            ));
+        }
+        Some(length) => {
+            let mut body = vec![0; length];
+            reader.read_exact(&mut body).map_err(malformed)?;
+            body
+        }
+        None if method == "POST" => {
+            return Err(Response::error(411, "Content-Length is required"));
+        }
+        None => Vec::new(),
+    };
+
+    // Queries are not used, so they are not an error either.
+    let path = target.split('?').next().unwrap_or_default().to_string();
+    Ok(Request { method, path, body })
+}
+
+/// Reads one line of the request head into `line`, out of the `left` bytes the head may
+/// still take up.
+fn read_head_line(
+    reader: &mut impl BufRead,
+    line: &mut String,
+    left: &mut u64,
+) -> Result<usize, Response> {
+    let read = Read::take(&mut *reader, *left)
+        .read_line(line)
+        .map_err(|_| Response::error(400, "malformed request"))?;
+    *left -= read as u64;
+    if *left == 0 && !line.ends_with('\n') {
+        return Err(Response::error(
+            431,
+            format!(
+                "request line and headers are larger than {} bytes",
+                MAX_HEAD
+            ),
+        ));
+    }
+    Ok(read)
+}
+
+/// Routes `POST /day/{n}/part/{p}` to the day's solver, giving up on it after `timeout`.
+pub fn respond(request: &Request, timeout: Duration) -> Response {
+    let Some((day, part)) = route(&request.path) else {
+        return Response::error(404, format!("no such resource `{}`", request.path));
+    };
+    let Some(puzzle) = aoc_days::find(day) else {
+        return Response::error(404, format!("day {} is not implemented", day));
+    };
+    if request.method != "POST" {
+        return Response::error(405, "post the puzzle input to solve it");
+    }
+    let Ok(input) = str::from_utf8(&request.body) else {
+        return Response::error(400, "input is not valid UTF-8");
+    };
+    solve(puzzle, part, input, timeout)
+}
+
+/// Solves `part` of `input` on the runner's pool, which gives up on it after `timeout`.
+fn solve(puzzle: &'static Puzzle, part: Part, input: &str, timeout: Duration) -> Response {
+    let owned = input.to_string();
+    let task: pool::Task<_> =
+        Box::new(move |token| cancel::with(token, || puzzle.run(&owned, &[part])));
+    let status = pool::run(vec![task], 1, Some(timeout))
+        .pop()
+        .expect("one task has one status");
+
+    match status {
+        Status::Done(Ok(report)) => {
+            let record = output::to_json(&[report])
+                .as_array()
+                .and_then(|records| records.first().cloned())
+                .expect("a report of one part has one record");
+            Response::ok(record)
+        }
+        Status::Done(Err(e)) => Response::parse_error(&e, input),
+        Status::Panicked(message) => Response::error(500, format!("solver panicked: {}", message)),
+        Status::TimedOut(limit) => {
+            Response::error(503, format!("solver timed out after {:.1?}", limit))
+        }
+    }
+}
+
+/// The day and part of a `/day/{n}/part/{p}` path.
+fn route(path: &str) -> Option<(u8, Part)> {
+    let mut segments = path.strip_prefix("/day/")?.split('/');
+    let day = segments.next()?.parse().ok()?;
+    let part = match (segments.next()?, segments.next()?) {
+        ("part", "1") => Part::One,
+        ("part", "2") => Part::Two,
+        _ => return None,
+    };
+    segments.next().is_none().then_some((day, part))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::SocketAddr;
+
+    use aoc_core::{Cancel, Solution, TimedOut};
+
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../day01/examples/example.txt");
+
+    /// Spins until cancelled, like a solver on an input far too large for it.
+    struct Slow;
+
+    impl Solution for Slow {
+        const DAY: u8 = 1;
+
+        type Input = ();
+        type Answer1 = Result<u64, TimedOut>;
+        type Answer2 = u64;
+
+        fn parse(_: &str) -> Result<Self::Input, ParseError> {
+            Ok(())
+        }
+
+        fn part1(_: &Self::Input) -> Self::Answer1 {
+            let cancel = Cancel::current();
+            loop {
+                cancel.check()?;
+                thread::sleep(Duration::from_millis(1));
+            }
+        }
+
+        fn part2(_: &Self::Input) -> Self::Answer2 {
+            0
+        }
+    }
+
+    static SLOW: Puzzle = Puzzle::of::<Slow>();
+
+    fn start_with(connections: usize) -> SocketAddr {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        thread::spawn(move || serve(listener, connections, Duration::from_secs(10)));
+        addr
+    }
+
+    fn start() -> SocketAddr {
+        start_with(16)
+    }
+
+    /// Sends `request` as is and returns the status, headers and JSON body of the response.
+    fn send(addr: SocketAddr, request: &[u8]) -> (u16, String, Json) {
+        let mut stream = TcpStream::connect(addr).unwrap();
+        stream.write_all(request).unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+
+        let (head, body) = response.split_once("\r\n\r\n").unwrap();
+        let status = head.split(' ').nth(1).unwrap().parse().unwrap();
+        (
+            status,
+            head.to_string(),
+            Json::parse(body.trim_end()).unwrap(),
+        )
+    }
+
+    fn post(addr: SocketAddr, path: &str, body: &str) -> (u16, Json) {
+        let request = format!(
+            "POST {} HTTP/1.1\r\nHost: localhost\r\nContent-Length: {}\r\n\r\n{}",
+            path,
+            body.len(),
+            body
+        );
+        let (status, _, json) = send(addr, request.as_bytes());
+        (status, json)
+    }
+
+    #[test]
+    fn test_solves_parts() {
+        let addr = start();
+
+        let (status, json) = post(addr, "/day/1/part/1", EXAMPLE);
+        assert_eq!(status, 200);
+        assert_eq!(json.get("day"), Some(&Json::from(1u64)));
+        assert_eq!(json.get("part"), Some(&Json::from(1u64)));
+        assert_eq!(json.get("answer"), Some(&Json::from(3u64)));
+        assert_eq!(json.get("answer_type"), Some(&Json::from("signed")));
+        assert!(json.get("solve_time_ns").and_then(Json::as_i128).is_some());
+
+        let (status, json) = post(addr, "/day/1/part/2?verbose", EXAMPLE);
+        assert_eq!(status, 200);
+        assert_eq!(json.get("answer"), Some(&Json::from(6u64)));
+    }
+
+    #[test]
+    fn test_parse_errors_are_bad_requests() {
+        let (status, json) = post(start(), "/day/1/part/1", "L68\nX30\n");
+        assert_eq!(status, 400);
+        assert_eq!(json.get("error"), Some(&Json::from("parse error")));
+        assert_eq!(json.get("line"), Some(&Json::from(2u64)));
+        assert_eq!(json.get("column"), Some(&Json::from(1u64)));
+        assert_eq!(json.get("token"), Some(&Json::from("X")));
+        assert_eq!(
+            json.get("expected"),
+            Some(&Json::from("a direction `L` or `R`"))
+        );
+        assert!(json.get("message").and_then(Json::as_str).is_some());
+    }
+
+    #[test]
+    fn test_errors() {
+        let addr = start();
+        assert_eq!(post(addr, "/day/99/part/1", "").0, 404);
+        assert_eq!(post(addr, "/day/1/part/3", "").0, 404);
+        assert_eq!(post(addr, "/day/1/part/1/extra", "").0, 404);
+        assert_eq!(post(addr, "/", "").0, 404);
+
+        let (status, head, _) = send(addr, b"GET /day/1/part/1 HTTP/1.1\r\n\r\n");
+        assert_eq!(status, 405);
+        assert!(head.contains("Allow: POST"));
+
+        let (status, _, json) = send(addr, b"POST /day/1/part/1 HTTP/1.1\r\n\r\n");
+        assert_eq!(status, 411);
+        assert_eq!(
+            json.get("error"),
+            Some(&Json::from("Content-Length is required"))
+        );
+
+        let (status, _, _) = send(
+            addr,
+            b"POST /day/1/part/1 HTTP/1.1\r\nContent-Length: 2\r\n\r\n\xff\xfe",
+        );
+        assert_eq!(status, 400);
+
+        let (status, _, _) = send(addr, b"nonsense\r\n\r\n");
+        assert_eq!(status, 400);
+    }
+
+    #[test]
+    fn test_solves_time_out() {
+        let response = solve(&SLOW, Part::One, "", Duration::from_millis(50));
+        assert_eq!(response.status, 503);
+        assert_eq!(
+            response.body.get("error"),
+            Some(&Json::from("solver timed out after 50.0ms"))
+        );
+        assert_eq!(
+            solve(&SLOW, Part::Two, "", Duration::from_secs(10)).status,
+            200
+        );
+    }
+
+    #[test]
+    fn test_connections_wait_for_a_free_slot() {
+        let addr = start_with(1);
+        // Holds the only slot by not finishing its request.
+        let mut first = TcpStream::connect(addr).unwrap();
+        first.write_all(b"POST /day/1/part/1 HTTP/1.1\r\n").unwrap();
+
+        let mut second = TcpStream::connect(addr).unwrap();
+        second.write_all(b"GET / HTTP/1.1\r\n\r\n").unwrap();
+        second
+            .set_read_timeout(Some(Duration::from_millis(200)))
+            .unwrap();
+        assert!(second.read(&mut [0; 1]).is_err());
+
+        first.write_all(b"\r\n").unwrap();
+        let mut response = String::new();
+        first.read_to_string(&mut response).unwrap();
+        assert!(response.starts_with("HTTP/1.1 411"));
+
+        second.set_read_timeout(None).unwrap();
+        let mut response = String::new();
+        second.read_to_string(&mut response).unwrap();
+        assert!(response.starts_with("HTTP/1.1 404"));
+    }
+
+    #[test]
+    fn test_read_request() {
+        let mut raw: &[u8] =
+            b"POST /day/3/part/2?x=1 HTTP/1.1\r\ncontent-length: 5\r\nHost: a\r\n\r\n12345extra";
+        assert_eq!(
+            read_request(&mut raw),
+            Ok(Request {
+                method: "POST".to_string(),
+                path: "/day/3/part/2".to_string(),
+                body: b"12345".to_vec(),
+            })
+        );
+
+        let mut raw: &[u8] = b"POST / HTTP/1.1\r\nContent-Length: 99999999999\r\n\r\n";
+        assert_eq!(read_request(&mut raw).unwrap_err().status, 413);
+        let mut raw: &[u8] = b"POST / HTTP/1.1\r\nContent-Length: 10\r\n\r\nshort";
+        assert_eq!(read_request(&mut raw).unwrap_err().status, 400);
+
+        let long = format!("GET /{} HTTP/1.1\r\n\r\n", "a".repeat(MAX_HEAD as usize));
+        assert_eq!(read_request(&mut long.as_bytes()).unwrap_err().status, 431);
+        let endless = format!("GET / HTTP/1.1\r\nX: {}", "a".repeat(MAX_HEAD as usize));
+        assert_eq!(
+            read_request(&mut endless.as_bytes()).unwrap_err().status,
+            431
+        );
+        let headers = "X: a\r\n".repeat(MAX_HEAD as usize / 5);
+        let many = format!("GET / HTTP/1.1\r\n{}\r\n", headers);
+        assert_eq!(read_request(&mut many.as_bytes()).unwrap_err().status, 431);
+    }
+
+    #[test]
+    fn test_route() {
+        assert_eq!(route("/day/12/part/1"), Some((12, Part::One)));
+        assert_eq!(route("/day/x/part/1"), None);
+        assert_eq!(route("/day/1/parts/1"), None);
+        assert_eq!(route("/day/1"), None);
+    }
+}
@@ -0,0 +1,186 @@
+use std::time::Duration;
+
+use aoc_core::{Part, Report};
+
+use crate::table::{Align, Table};
+
+pub enum Outcome {
+    Solved(Report),
+    Failed {
+        day: u8,
+        /// The part that failed, or `None` if the whole day did.
+        part: Option<Part>,
+        reason: String,
+        /// Multi-line diagnostics printed below the table.
+        details: Option<String>,
+    },
+    TimedOut {
+        day: u8,
+        part: Option<Part>,
+        limit: Duration,
+    },
+}
+
+impl Outcome {
+    pub fn is_failure(&self) -> bool {
+        !matches!(self, Outcome::Solved(_))
+    }
+
+    pub fn day(&self) -> u8 {
+        match self {
+            Outcome::Solved(report) => report.day,
+            Outcome::Failed { day, .. } | Outcome::TimedOut { day, .. } => *day,
+        }
+    }
+}
+
+fn part_column(part: Option<Part>) -> String {
+    part.map_or("-".to_string(), |part| part.number().to_string())
+}
+
+/// Prints one row per solved part, with a column naming the strategy if `show_strategy`,
+/// and a summary line with the total time spent solving and the `wall` time it took.
+pub fn print_table(outcomes: &[Outcome], show_strategy: bool, wall: Duration) {
+    let mut columns = vec![("Day", Align::Right), ("Part", Align::Right)];
+    if show_strategy {
+        columns.push(("Strategy", Align::Left));
+    }
+    columns.extend([("Answer", Align::Left), ("Time", Align::Right)]);
+    let mut table = Table::new(&columns);
+
+    for outcome in outcomes {
+        match outcome {
+            Outcome::Solved(report) => {
+                let disagreements = report.disagreements();
+                for part in &report.parts {
+                    let mut row = vec![report.day.to_string(), part.part.number().to_string()];
+                    if show_strategy {
+                        row.push(part.strategy.to_string());
+                    }
+                    let mut answer = part.answer.to_string();
+                    if disagreements.contains(&part.part) {
+                        answer.push_str("  MISMATCH");
+                    }
+                    row.extend([answer, format_duration(part.time)]);
+                    table.push(row);
+                }
+            }
+            Outcome::Failed {
+                day, part, reason, ..
+            } => {
+                let mut row = vec![day.to_string(), part_column(*part)];
+                if show_strategy {
+                    row.push("-".to_string());
+                }
+                row.extend([format!("FAILED: {}", reason), "-".to_string()]);
+                table.push(row);
+            }
+            Outcome::TimedOut { day, part, limit } => {
+                let mut row = vec![day.to_string(), part_column(*part)];
+                if show_strategy {
+                    row.push("-".to_string());
+                }
+                row.extend([
+                    "TIMED OUT".to_string(),
+                    format!("> {}", format_duration(*limit)),
+                ]);
+                table.push(row);
+            }
+        }
+    }
+
+    print!("{}", table);
+
+    let mut days: Vec<u8> = outcomes.iter().map(Outcome::day).collect();
+    days.dedup();
+    let failed = outcomes
+        .iter()
+        .filter(|o| matches!(o, Outcome::Failed { .. }))
+        .count();
+    let timed_out = outcomes
+        .iter()
+        .filter(|o| matches!(o, Outcome::TimedOut { .. }))
+        .count();
+    let total: Duration = outcomes
+        .iter()
+        .filter_map(|outcome| match outcome {
+            Outcome::Solved(report) => {
+                Some(report.parse_time + report.parts.iter().map(|p| p.time).sum::<Duration>())
+            }
+            Outcome::Failed { .. } | Outcome::TimedOut { .. } => None,
+        })
+        .sum();
+
+    println!();
+    println!(
+        "{} day(s) run, {} failed, {} timed out, total time {}, wall time {}",
+        days.len(),
+        failed,
+        timed_out,
+        format_duration(total),
+        format_duration(wall)
+    );
+}
+
+/// Prints the trace of every explained part, after the table.
+pub fn print_traces(outcomes: &[Outcome]) {
+    for outcome in outcomes {
+        let Outcome::Solved(report) = outcome else {
+            continue;
+        };
+        for part in report.parts.iter().filter(|part| !part.trace.is_empty()) {
+            println!();
+            println!(
+                "Day {} part {} ({}):",
+                report.day,
+                part.part.number(),
+                part.strategy
+            );
+            for event in &part.trace {
+                println!("  {}", event);
+            }
+        }
+    }
+}
+
+pub fn print_failure_details(outcomes: &[Outcome]) {
+    for outcome in outcomes {
+        if let Outcome::Failed {
+            day,
+            details: Some(details),
+            ..
+        } = outcome
+        {
+            eprintln!();
+            eprintln!("Day {}: {}", day, details);
+        }
+    }
+}
+
+/// Reports every failure on stderr, for output formats that have no room for them.
+pub fn print_failures(outcomes: &[Outcome]) {
+    for outcome in outcomes {
+        let (day, part, message) = match outcome {
+            Outcome::Solved(_) => continue,
+            Outcome::Failed {
+                day,
+                part,
+                reason,
+                details,
+            } => (day, part, details.clone().unwrap_or_else(|| reason.clone())),
+            Outcome::TimedOut { day, part, limit } => (
+                day,
+                part,
+                format!("timed out after {}", format_duration(*limit)),
+            ),
+        };
+        match part {
+            Some(part) => eprintln!("Day {} part {}: {}", day, part.number(), message),
+            None => eprintln!("Day {}: {}", day, message),
+        }
+    }
+}
+
+pub fn format_duration(duration: Duration) -> String {
+    format!("{:.2?}", duration)
+}
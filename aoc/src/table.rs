@@ -0,0 +1,75 @@
+use std::fmt;
+
+#[derive(Debug, Clone, Copy)]
+pub enum Align {
+    Left,
+    Right,
+}
+
+/// A plain-text table whose columns are padded to their widest cell.
+pub struct Table {
+    columns: Vec<(String, Align)>,
+    rows: Vec<Vec<String>>,
+}
+
+impl Table {
+    pub fn new(columns: &[(&str, Align)]) -> Self {
+        Table {
+            columns: columns
+                .iter()
+                .map(|&(name, align)| (name.to_string(), align))
+                .collect(),
+            rows: Vec::new(),
+        }
+    }
+
+    pub fn push(&mut self, row: Vec<String>) {
+        self.rows.push(row);
+    }
+}
+
+impl fmt::Display for Table {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let header: Vec<String> = self.columns.iter().map(|(name, _)| name.clone()).collect();
+        let rows: Vec<&Vec<String>> = std::iter::once(&header).chain(&self.rows).collect();
+
+        let mut widths = vec![0; self.columns.len()];
+        for row in &rows {
+            for (width, cell) in widths.iter_mut().zip(row.iter()) {
+                *width = (*width).max(cell.chars().count());
+            }
+        }
+
+        for row in rows {
+            let mut line = String::new();
+            for (i, ((cell, width), (_, align))) in
+                row.iter().zip(&widths).zip(&self.columns).enumerate()
+            {
+                if i > 0 {
+                    line.push_str("  ");
+                }
+                match align {
+                    Align::Left => line.push_str(&format!("{:<width$}", cell, width = width)),
+                    Align::Right => line.push_str(&format!("{:>width$}", cell, width = width)),
+                }
+            }
+            writeln!(f, "{}", line.trim_end())?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        let mut table = Table::new(&[("Day", Align::Right), ("Answer", Align::Left)]);
+        table.push(vec!["1".to_string(), "1227775554".to_string()]);
+        table.push(vec!["12".to_string(), "-".to_string()]);
+
+        assert_eq!(table.to_string(), "Day  Answer\n  1  1227775554\n 12  -\n");
+    }
+}
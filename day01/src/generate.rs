@@ -0,0 +1,22 @@
+//! Random puzzle inputs for property tests.
+
+use rand::{Rng, SeedableRng, rngs::StdRng};
+
+/// `size` random rotations, reproducible from `seed`. Distances include zero, exact
+/// multiples of the dial size and several full turns.
+pub fn input(seed: u64, size: usize) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+
+    (0..size)
+        .map(|_| {
+            let direction = if rng.random_bool(0.5) { 'L' } else { 'R' };
+            let distance = match rng.random_range(0..10) {
+                0 => 0,
+                1 => 100 * rng.random_range(1..5),
+                2 => rng.random_range(100..1000),
+                _ => rng.random_range(1..100),
+            };
+            format!("{}{}\n", direction, distance)
+        })
+        .collect()
+}
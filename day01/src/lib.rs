@@ -0,0 +1,545 @@
+use std::{fmt, slice};
+
+use aoc_core::{
+    Line, ParseError, Part, Solution, Strategy, lines,
+    trace::{self, Event, Verbosity},
+};
+
+#[cfg(feature = "generate")]
+pub mod generate;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    Left,
+    Right,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rotation {
+    pub direction: Direction,
+    pub distance: u32,
+    /// 1-based line number of the rotation in its input, as in parse errors.
+    pub line: usize,
+}
+
+impl fmt::Display for Rotation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let direction = match self.direction {
+            Direction::Left => 'L',
+            Direction::Right => 'R',
+        };
+        write!(f, "{}{}", direction, self.distance)
+    }
+}
+
+impl Rotation {
+    pub fn parse(line: Line) -> Result<Self, ParseError> {
+        let text = line.text.trim();
+        let (dir, distance_str) = text.split_at(text.chars().next().map_or(0, char::len_utf8));
+        let direction = match dir {
+            "L" => Direction::Left,
+            "R" => Direction::Right,
+            _ => return Err(line.error(dir, "a direction `L` or `R`")),
+        };
+        let distance = line.parse(distance_str, "a distance")?;
+
+        Ok(Rotation {
+            direction,
+            distance,
+            line: line.number,
+        })
+    }
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+
+    type Input = Vec<Rotation>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(rotations: &Self::Input) -> Self::Answer1 {
+        part1(rotations)
+    }
+
+    fn part2(rotations: &Self::Input) -> Self::Answer2 {
+        part2(rotations)
+    }
+
+    fn strategies() -> Vec<Strategy<Self::Input>> {
+        vec![
+            Strategy::new(Part::Two, "formula", |rotations| part2(rotations).into()),
+            Strategy::new(Part::Two, "clicks", |rotations| {
+                part2_clicks(rotations).into()
+            }),
+        ]
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<Rotation>, ParseError> {
+    lines(input).map(Rotation::parse).collect()
+}
+
+/// A lock dial numbered `0..positions`, pointing at `position`. The fields are only set
+/// through `new` and the rotations, so the dial always points at one of its positions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Dial {
+    positions: u32,
+    position: u32,
+}
+
+/// What one rotation of a `Dial` did.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RotationOutcome {
+    /// Where the dial points afterwards.
+    pub position: u32,
+    /// 1 if the dial stopped at zero, else 0.
+    pub landings: u64,
+    /// Clicks that brought the dial to zero, the last one included when it stops there.
+    pub crossings: u64,
+}
+
+impl Dial {
+    pub fn new(positions: u32, start: u32) -> Self {
+        assert!(
+            start < positions,
+            "start {} is not on a dial of {} positions",
+            start,
+            positions
+        );
+        Dial {
+            positions,
+            position: start,
+        }
+    }
+
+    pub fn positions(&self) -> u32 {
+        self.positions
+    }
+
+    pub fn position(&self) -> u32 {
+        self.position
+    }
+
+    pub fn rotate(&mut self, rotation: Rotation) -> RotationOutcome {
+        self.turn(rotation.direction, u64::from(rotation.distance))
+    }
+
+    /// `rotate` by a distance that may not fit a `Rotation`, as a geared dial in a `Lock`
+    /// can be turned.
+    fn turn(&mut self, direction: Direction, distance: u64) -> RotationOutcome {
+        let (positions, position) = (u64::from(self.positions), u64::from(self.position));
+
+        let (crossings, new_position) = match direction {
+            Direction::Left => {
+                // Turning left from `position` reaches zero after `position` clicks, then
+                // every `positions` clicks; from zero itself, only after a full turn.
+                let crossings = if position > 0 && distance >= position {
+                    (distance - position) / positions + 1
+                } else if position == 0 {
+                    distance / positions
+                } else {
+                    0
+                };
+                let back = distance % positions;
+                (crossings, (position + positions - back) % positions)
+            }
+            Direction::Right => (
+                (position + distance) / positions,
+                (position + distance) % positions,
+            ),
+        };
+
+        self.position = new_position as u32;
+        RotationOutcome {
+            position: self.position,
+            landings: u64::from(self.position == 0),
+            crossings,
+        }
+    }
+
+    /// `rotate` one click at a time: slow, but plainly right, to check `rotate` against.
+    pub fn rotate_by_clicks(&mut self, rotation: Rotation) -> RotationOutcome {
+        let positions = u64::from(self.positions);
+        let step = match rotation.direction {
+            Direction::Left => positions - 1,
+            Direction::Right => 1,
+        };
+        let mut position = u64::from(self.position);
+        let mut crossings = 0;
+        for _ in 0..rotation.distance {
+            position = (position + step) % positions;
+            crossings += u64::from(position == 0);
+        }
+
+        self.position = position as u32;
+        RotationOutcome {
+            position: self.position,
+            landings: u64::from(position == 0),
+            crossings,
+        }
+    }
+}
+
+/// What happened on one line of the input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RotationEvent {
+    /// The rotation, with the line it came from.
+    pub rotation: Rotation,
+    /// Where the dial pointed before the rotation.
+    pub from: u32,
+    pub outcome: RotationOutcome,
+}
+
+/// The events of turning a dial through rotations, one per rotation in order.
+#[derive(Debug, Clone)]
+pub struct Events<'a> {
+    dial: Dial,
+    rotations: slice::Iter<'a, Rotation>,
+}
+
+impl Iterator for Events<'_> {
+    type Item = RotationEvent;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let &rotation = self.rotations.next()?;
+        let from = self.dial.position;
+        Some(RotationEvent {
+            rotation,
+            from,
+            outcome: self.dial.rotate(rotation),
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.rotations.size_hint()
+    }
+}
+
+impl ExactSizeIterator for Events<'_> {}
+
+impl Dial {
+    /// Turns a copy of this dial through `rotations`, one event per rotation.
+    pub fn events(self, rotations: &[Rotation]) -> Events<'_> {
+        Events {
+            dial: self,
+            rotations: rotations.iter(),
+        }
+    }
+}
+
+/// The puzzle's dial: 100 positions, starting at 50.
+impl Default for Dial {
+    fn default() -> Self {
+        Dial::new(100, 50)
+    }
+}
+
+/// Which zeros to count: the rotations that stop there (part 1), or every click that
+/// reaches it (part 2).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Zeros {
+    Landings,
+    Crossings,
+}
+
+/// How many zeros `rotations` hit on a dial of `positions` positions, for each start
+/// position in turn.
+///
+/// Rather than turning the dial once per start, this works with the offsets of the
+/// rotations' prefix sums `p`: from start `s` the dial is at `(s + p) mod positions`, so
+/// the landings of every start come from one histogram of `-p mod positions`. Crossings
+/// are differences of `floor((s + p) / positions)`, each a constant plus a step up at one
+/// start, which a difference array adds up. Both take O(positions + rotations).
+///
+/// Panics if `positions` is zero, as `Dial::new` does for a dial without positions.
+pub fn zeros_by_start(rotations: &[Rotation], positions: u32, zeros: Zeros) -> Vec<u64> {
+    assert!(positions > 0, "a dial needs at least one position");
+    let n = i64::from(positions);
+    // counts[s] - counts[s - 1], so that a step up from start `s` on is one entry.
+    let mut steps = vec![0i64; positions as usize + 1];
+    let mut offset = 0i64;
+
+    // Adds `sign * floor((s + c) / n)` to every start `s`.
+    let add_floor = |steps: &mut [i64], c: i64, sign: i64| {
+        steps[0] += sign * c.div_euclid(n);
+        let r = c.rem_euclid(n);
+        if r > 0 {
+            steps[(n - r) as usize] += sign;
+        }
+    };
+
+    for rotation in rotations {
+        let distance = i64::from(rotation.distance);
+        let from = offset;
+        offset += match rotation.direction {
+            Direction::Left => -distance,
+            Direction::Right => distance,
+        };
+        match zeros {
+            Zeros::Landings => {
+                let start = (-offset).rem_euclid(n) as usize;
+                steps[start] += 1;
+                steps[start + 1] -= 1;
+            }
+            // Right, the clicks land on (s + from, s + offset]; left, on [s + offset, s + from).
+            Zeros::Crossings => match rotation.direction {
+                Direction::Right => {
+                    add_floor(&mut steps, offset, 1);
+                    add_floor(&mut steps, from, -1);
+                }
+                Direction::Left => {
+                    add_floor(&mut steps, from - 1, 1);
+                    add_floor(&mut steps, offset - 1, -1);
+                }
+            },
+        }
+    }
+
+    steps[..positions as usize]
+        .iter()
+        .scan(0, |count, step| {
+            *count += step;
+            Some(*count as u64)
+        })
+        .collect()
+}
+
+/// The start positions, in order, from which `rotations` hit exactly `target` zeros.
+///
+/// Panics if `positions` is zero, through `zeros_by_start`.
+pub fn starts_with_zeros(
+    rotations: &[Rotation],
+    positions: u32,
+    zeros: Zeros,
+    target: u64,
+) -> Vec<u32> {
+    zeros_by_start(rotations, positions, zeros)
+        .into_iter()
+        .zip(0..)
+        .filter(|&(count, _)| count == target)
+        .map(|(_, start)| start)
+        .collect()
+}
+
+/// A dial of a `Lock`, turned `ratio` clicks for every click of the lock's input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GearedDial {
+    pub dial: Dial,
+    pub ratio: u32,
+}
+
+/// Concentric dials driven together by one stream of rotations through their gearing.
+/// The dials are only set through `new`, so a lock always has at least one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Lock {
+    dials: Vec<GearedDial>,
+}
+
+/// A moment when every dial of a `Lock` read zero at once: `click` input clicks into the
+/// rotation on line `line`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AllZero {
+    /// 1-based line number of the rotation, as in parse errors.
+    pub line: usize,
+    /// Input clicks into the rotation, from 1 to its distance.
+    pub click: u64,
+}
+
+/// What turning a `Lock` through rotations did.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LockReport {
+    /// Rotations that left each dial at zero, in the order of `Lock::dials()`.
+    pub landings: Vec<u64>,
+    /// Clicks of each dial's own that brought it to zero.
+    pub crossings: Vec<u64>,
+    /// Every moment all dials read zero, in order. Dials are only compared between input
+    /// clicks, not partway through the several clicks a geared dial makes per input click.
+    pub all_zero: Vec<AllZero>,
+}
+
+impl Lock {
+    pub fn new(dials: Vec<GearedDial>) -> Self {
+        assert!(!dials.is_empty(), "a lock needs at least one dial");
+        Lock { dials }
+    }
+
+    /// The dials in the order `new` was given them, where the last `run` left them.
+    pub fn dials(&self) -> &[GearedDial] {
+        &self.dials
+    }
+
+    /// Turns every dial through `rotations`, each by its rotation's distance times its
+    /// ratio, and leaves the dials where they stop.
+    pub fn run(&mut self, rotations: &[Rotation]) -> LockReport {
+        let mut report = LockReport {
+            landings: vec![0; self.dials.len()],
+            crossings: vec![0; self.dials.len()],
+            all_zero: Vec::new(),
+        };
+
+        for &rotation in rotations {
+            report
+                .all_zero
+                .extend(
+                    self.all_zero_clicks(rotation)
+                        .into_iter()
+                        .map(|click| AllZero {
+                            line: rotation.line,
+                            click,
+                        }),
+                );
+            for (i, geared) in self.dials.iter_mut().enumerate() {
+                let distance = u64::from(rotation.distance) * u64::from(geared.ratio);
+                let outcome = geared.dial.turn(rotation.direction, distance);
+                report.landings[i] += outcome.landings;
+                report.crossings[i] += outcome.crossings;
+            }
+        }
+        report
+    }
+
+    /// The input clicks of `rotation`, from the current positions, after which every dial
+    /// reads zero.
+    ///
+    /// Each dial reads zero every `period` input clicks from its first zero, if ever, so
+    /// only the zeros of the dial with the longest period need checking against the rest.
+    fn all_zero_clicks(&self, rotation: Rotation) -> Vec<u64> {
+        let Some(progressions) = self
+            .dials
+            .iter()
+            .map(|geared| geared.zero_clicks(rotation.direction))
+            .collect::<Option<Vec<_>>>()
+        else {
+            return Vec::new();
+        };
+        let &(first, period) = progressions
+            .iter()
+            .max_by_key(|&&(_, period)| period)
+            .expect("a lock has at least one dial");
+
+        (first..=u64::from(rotation.distance))
+            .step_by(period as usize)
+            .filter(|&click| {
+                progressions
+                    .iter()
+                    .all(|&(first, period)| click >= first && (click - first) % period == 0)
+            })
+            .collect()
+    }
+}
+
+impl GearedDial {
+    /// `(first, period)`: the input clicks in `direction` after which the dial reads zero
+    /// are `first`, `first + period` and so on, or there are none.
+    fn zero_clicks(&self, direction: Direction) -> Option<(u64, u64)> {
+        let n = i128::from(self.dial.positions);
+        let (ratio, position) = (i128::from(self.ratio), i128::from(self.dial.position));
+        // After `j` input clicks the dial is at `position -/+ j * ratio`, which is zero when
+        // `ratio * j ≡ target (mod n)`.
+        let target = match direction {
+            Direction::Left => position,
+            Direction::Right => -position,
+        };
+        let (g, inverse) = gcd_inverse(ratio.rem_euclid(n), n);
+        if target.rem_euclid(g) != 0 {
+            return None;
+        }
+        let period = n / g;
+        let first = match (inverse * (target / g)).rem_euclid(period) {
+            0 => period,
+            first => first,
+        };
+        Some((first as u64, period as u64))
+    }
+}
+
+/// `(gcd(a, b), x)` with `a * x ≡ gcd(a, b) (mod b)`, by the extended Euclidean algorithm.
+fn gcd_inverse(a: i128, b: i128) -> (i128, i128) {
+    let (mut r0, mut r1) = (a, b);
+    let (mut x0, mut x1) = (1, 0);
+    while r1 != 0 {
+        let q = r0 / r1;
+        (r0, r1) = (r1, r0 - q * r1);
+        (x0, x1) = (x1, x0 - q * x1);
+    }
+    (r0, x0)
+}
+
+/// Turns the puzzle's dial through `rotations`, adding up `zeros` of each rotation.
+fn count_zeros(
+    rotations: &[Rotation],
+    name: &'static str,
+    zeros: fn(&RotationOutcome) -> u64,
+) -> i64 {
+    let mut total = 0;
+
+    for event in Dial::default().events(rotations) {
+        total += zeros(&event.outcome);
+        trace::emit(Verbosity::Steps, || {
+            Event::new("rotate")
+                .with("rotation", event.rotation)
+                .with("from", event.from)
+                .with("to", event.outcome.position)
+                .with("zeros", zeros(&event.outcome))
+        });
+    }
+
+    trace::emit(Verbosity::Summary, || Event::new("zeros").with(name, total));
+    total as i64
+}
+
+pub fn part1(rotations: &[Rotation]) -> i64 {
+    count_zeros(rotations, "landings", |outcome| outcome.landings)
+}
+
+pub fn part2(rotations: &[Rotation]) -> i64 {
+    count_zeros(rotations, "clicks", |outcome| outcome.crossings)
+}
+
+/// `part2` by turning the dial one click at a time.
+pub fn part2_clicks(rotations: &[Rotation]) -> i64 {
+    let mut dial = Dial::default();
+    let clicks: u64 = rotations
+        .iter()
+        .map(|&rotation| dial.rotate_by_clicks(rotation).crossings)
+        .sum();
+    clicks as i64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../examples/example.txt");
+
+    aoc_core::example_tests!(Day01);
+
+    #[test]
+    fn test_explain() {
+        let rotations = parse(EXAMPLE).unwrap();
+        let (_, events) = trace::explain(Verbosity::Steps, || trace::capture(|| part2(&rotations)));
+        let lines: Vec<String> = events.iter().map(Event::to_string).collect();
+        assert_eq!(lines.len(), 11);
+        assert_eq!(lines[0], "  rotate rotation=L68 from=50 to=82 zeros=1");
+        assert_eq!(lines[10], "zeros clicks=6");
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            parse("L68\nX30").unwrap_err(),
+            ParseError::new(2, 1, "X", "a direction `L` or `R`")
+        );
+        assert_eq!(
+            parse("L68\nR4x").unwrap_err(),
+            ParseError::new(2, 2, "4x", "a distance")
+        );
+    }
+}
@@ -0,0 +1,149 @@
+use day01::{
+    Dial, Direction, Rotation, RotationEvent, RotationOutcome, Zeros, parse, starts_with_zeros,
+    zeros_by_start,
+};
+
+fn rotation(direction: Direction, distance: u32) -> Rotation {
+    Rotation {
+        direction,
+        distance,
+        line: 1,
+    }
+}
+
+#[test]
+fn test_rotate() {
+    let mut dial = Dial::default();
+    assert_eq!(
+        dial.rotate(rotation(Direction::Left, 68)),
+        RotationOutcome {
+            position: 82,
+            landings: 0,
+            crossings: 1
+        }
+    );
+    assert_eq!(
+        dial.rotate(rotation(Direction::Right, 18)),
+        RotationOutcome {
+            position: 0,
+            landings: 1,
+            crossings: 1
+        }
+    );
+    // From zero, a full turn is needed to reach zero again.
+    assert_eq!(
+        dial.rotate(rotation(Direction::Left, 99)),
+        RotationOutcome {
+            position: 1,
+            landings: 0,
+            crossings: 0
+        }
+    );
+    assert_eq!(dial.rotate(rotation(Direction::Left, 301)).crossings, 4);
+    assert_eq!(dial.position(), 0);
+}
+
+#[test]
+fn test_other_sizes() {
+    let mut dial = Dial::new(7, 3);
+    assert_eq!(
+        dial.rotate(rotation(Direction::Right, 11)),
+        RotationOutcome {
+            position: 0,
+            landings: 1,
+            crossings: 2
+        }
+    );
+    assert_eq!(dial.rotate(rotation(Direction::Left, 7)).crossings, 1);
+    assert_eq!(dial.rotate(rotation(Direction::Left, 0)).landings, 1);
+
+    let mut dial = Dial::new(1, 0);
+    assert_eq!(dial.rotate(rotation(Direction::Left, 5)).crossings, 5);
+}
+
+#[test]
+#[should_panic(expected = "start 10 is not on a dial of 10 positions")]
+fn test_start_off_the_dial() {
+    Dial::new(10, 10);
+}
+
+#[test]
+fn test_events() {
+    let rotations = parse(include_str!("../examples/example.txt")).unwrap();
+    let events: Vec<RotationEvent> = Dial::default().events(&rotations).collect();
+
+    assert_eq!(events.len(), rotations.len());
+    assert_eq!(
+        events[0],
+        RotationEvent {
+            rotation: rotations[0],
+            from: 50,
+            outcome: RotationOutcome {
+                position: 82,
+                landings: 0,
+                crossings: 1
+            }
+        }
+    );
+    assert!(
+        events
+            .windows(2)
+            .all(|w| w[0].outcome.position == w[1].from)
+    );
+
+    let landed: Vec<usize> = events
+        .iter()
+        .filter(|event| event.outcome.landings > 0)
+        .map(|event| event.rotation.line)
+        .collect();
+    assert_eq!(landed, [3, 6, 8]);
+    let crossed: Vec<usize> = events
+        .iter()
+        .filter(|event| event.outcome.crossings > 0)
+        .map(|event| event.rotation.line)
+        .collect();
+    assert_eq!(crossed, [1, 3, 5, 6, 8, 10]);
+
+    // The lines are the rotations' own, not their places in the slice.
+    let lines: Vec<usize> = Dial::default()
+        .events(&rotations[7..])
+        .map(|event| event.rotation.line)
+        .collect();
+    assert_eq!(lines, [8, 9, 10]);
+}
+
+#[test]
+fn test_starts_with_zeros() {
+    let rotations = parse(include_str!("../examples/example.txt")).unwrap();
+
+    let landings = zeros_by_start(&rotations, 100, Zeros::Landings);
+    let crossings = zeros_by_start(&rotations, 100, Zeros::Crossings);
+    assert_eq!((landings[50], crossings[50]), (3, 6));
+
+    let starts = starts_with_zeros(&rotations, 100, Zeros::Landings, 3);
+    assert!(starts.contains(&50));
+    for start in starts {
+        let landed = Dial::new(100, start)
+            .events(&rotations)
+            .filter(|event| event.outcome.landings > 0)
+            .count();
+        assert_eq!(landed, 3, "start {}", start);
+    }
+
+    assert_eq!(
+        starts_with_zeros(&rotations, 100, Zeros::Crossings, 1000),
+        []
+    );
+}
+
+#[test]
+#[should_panic(expected = "a dial needs at least one position")]
+fn test_zeros_on_a_dial_without_positions() {
+    zeros_by_start(&parse("R1").unwrap(), 0, Zeros::Crossings);
+}
+
+#[test]
+#[should_panic(expected = "a dial needs at least one position")]
+fn test_starts_on_a_dial_without_positions() {
+    starts_with_zeros(&[], 0, Zeros::Landings, 0);
+}
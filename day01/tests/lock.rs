@@ -0,0 +1,83 @@
+use day01::{AllZero, Dial, GearedDial, Lock, LockReport, parse, part1, part2};
+
+const EXAMPLE: &str = include_str!("../examples/example.txt");
+
+fn geared(positions: u32, start: u32, ratio: u32) -> GearedDial {
+    GearedDial {
+        dial: Dial::new(positions, start),
+        ratio,
+    }
+}
+
+#[test]
+fn test_one_dial_is_the_puzzle() {
+    let rotations = parse(EXAMPLE).unwrap();
+    let report = Lock::new(vec![geared(100, 50, 1)]).run(&rotations);
+    assert_eq!(report.landings, [part1(&rotations) as u64]);
+    assert_eq!(report.crossings, [part2(&rotations) as u64]);
+    assert_eq!(
+        report
+            .all_zero
+            .iter()
+            .map(|moment| moment.line)
+            .collect::<Vec<_>>(),
+        [1, 3, 5, 6, 8, 10]
+    );
+}
+
+#[test]
+fn test_geared_dials() {
+    // The small dial reads zero every other input click, the large one at 2, 6, 10...
+    let mut lock = Lock::new(vec![geared(4, 2, 1), geared(6, 0, 3)]);
+    let rotations = parse("R10\nL3\nL1\n").unwrap();
+    assert_eq!(
+        lock.run(&rotations),
+        LockReport {
+            landings: vec![2, 2],
+            crossings: vec![4, 7],
+            all_zero: vec![
+                AllZero { line: 1, click: 2 },
+                AllZero { line: 1, click: 6 },
+                AllZero { line: 1, click: 10 },
+                AllZero { line: 3, click: 1 },
+            ],
+        }
+    );
+    assert_eq!(
+        lock.dials()
+            .iter()
+            .map(|geared| geared.dial.position())
+            .collect::<Vec<_>>(),
+        [0, 0]
+    );
+}
+
+#[test]
+fn test_all_zero_lines_are_the_inputs() {
+    let rotations = parse("R10\nL3\nL1\n").unwrap();
+    let report = Lock::new(vec![geared(4, 2, 1), geared(6, 0, 3)]).run(&rotations[1..]);
+    assert_eq!(report.all_zero, [AllZero { line: 2, click: 2 }]);
+}
+
+#[test]
+fn test_dials_that_never_line_up() {
+    // Zero on the first dial after odd clicks, on the second after even ones.
+    let mut lock = Lock::new(vec![geared(2, 1, 1), geared(2, 0, 1)]);
+    let report = lock.run(&parse("R7\nL4").unwrap());
+    assert_eq!(report.crossings, [6, 5]);
+    assert_eq!(report.all_zero, []);
+
+    // A dial geared at 0 never moves. The other passes zero on its own clicks, but
+    // between input clicks it only ever reads odd numbers.
+    let mut lock = Lock::new(vec![geared(10, 0, 0), geared(10, 5, 2)]);
+    let report = lock.run(&parse("R100").unwrap());
+    assert_eq!(report.landings, [1, 0]);
+    assert_eq!(report.crossings, [0, 20]);
+    assert_eq!(report.all_zero, []);
+}
+
+#[test]
+#[should_panic(expected = "a lock needs at least one dial")]
+fn test_lock_without_dials() {
+    Lock::new(Vec::new());
+}
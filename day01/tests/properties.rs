@@ -0,0 +1,201 @@
+use day01::{
+    AllZero, Dial, Direction, GearedDial, Lock, LockReport, Rotation, Zeros, generate, parse,
+    part1, part2, part2_clicks, zeros_by_start,
+};
+use rand::{Rng, SeedableRng, rngs::StdRng};
+
+/// Turns the dial one click at a time, counting zeros after each rotation and after
+/// each click.
+fn simulate(rotations: &[Rotation]) -> (i64, i64) {
+    let mut position = 50;
+    let mut landings = 0;
+    let mut clicks = 0;
+
+    for rotation in rotations {
+        let step = match rotation.direction {
+            Direction::Left => 99,
+            Direction::Right => 1,
+        };
+        for _ in 0..rotation.distance {
+            position = (position + step) % 100;
+            if position == 0 {
+                clicks += 1;
+            }
+        }
+        if position == 0 {
+            landings += 1;
+        }
+    }
+
+    (landings, clicks)
+}
+
+#[test]
+fn test_parts_match_click_simulation() {
+    for seed in 0..2000 {
+        let input = generate::input(seed, 1 + seed as usize % 30);
+        let rotations = parse(&input).unwrap();
+        let (landings, clicks) = simulate(&rotations);
+
+        assert_eq!(part1(&rotations), landings, "seed {}:\n{}", seed, input);
+        assert_eq!(part2(&rotations), clicks, "seed {}:\n{}", seed, input);
+        assert_eq!(
+            part2_clicks(&rotations),
+            clicks,
+            "seed {}:\n{}",
+            seed,
+            input
+        );
+    }
+}
+
+/// A rotation whose distance is often zero or near a multiple of the dial size, where
+/// the closed form's boundary cases are.
+fn random_rotation(rng: &mut StdRng, positions: u32, line: usize) -> Rotation {
+    let direction = if rng.random_bool(0.5) {
+        Direction::Left
+    } else {
+        Direction::Right
+    };
+    let turns = rng.random_range(0..5);
+    let distance = match rng.random_range(0..5) {
+        0 => 0,
+        1 => positions * turns,
+        2 => (positions * turns).saturating_sub(1),
+        3 => positions * turns + 1,
+        _ => rng.random_range(0..positions * 5),
+    };
+    Rotation {
+        direction,
+        distance,
+        line,
+    }
+}
+
+#[test]
+fn test_rotate_matches_clicks() {
+    for seed in 0..5000 {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let positions = match rng.random_range(0..4) {
+            0 => rng.random_range(1..4),
+            1 => 100,
+            _ => rng.random_range(1..300),
+        };
+        let start = match rng.random_range(0..3) {
+            0 => 0,
+            1 => positions - 1,
+            _ => rng.random_range(0..positions),
+        };
+
+        let mut dial = Dial::new(positions, start);
+        for _ in 0..10 {
+            let rotation = random_rotation(&mut rng, positions, 1);
+            let before = dial;
+            let mut reference = dial;
+            assert_eq!(
+                dial.rotate(rotation),
+                reference.rotate_by_clicks(rotation),
+                "seed {}: {} from {:?}",
+                seed,
+                rotation,
+                before
+            );
+        }
+    }
+}
+
+#[test]
+fn test_zeros_by_start_matches_turning_the_dial() {
+    for seed in 0..300 {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let positions = rng.random_range(1..60);
+        let rotations: Vec<Rotation> = (1..=rng.random_range(0..20))
+            .map(|line| random_rotation(&mut rng, positions, line))
+            .collect();
+
+        let landings = zeros_by_start(&rotations, positions, Zeros::Landings);
+        let crossings = zeros_by_start(&rotations, positions, Zeros::Crossings);
+        for start in 0..positions {
+            let (mut landed, mut crossed) = (0, 0);
+            for event in Dial::new(positions, start).events(&rotations) {
+                landed += event.outcome.landings;
+                crossed += event.outcome.crossings;
+            }
+            assert_eq!(
+                (landings[start as usize], crossings[start as usize]),
+                (landed, crossed),
+                "seed {}: start {} of {} through {:?}",
+                seed,
+                start,
+                positions,
+                rotations
+            );
+        }
+    }
+}
+
+/// Turns every dial of `lock` one click at a time, `ratio` clicks per input click, and
+/// compares the dials after each input click.
+fn simulate_lock(lock: &Lock, rotations: &[Rotation]) -> LockReport {
+    let mut dials: Vec<GearedDial> = lock.dials().to_vec();
+    let mut report = LockReport {
+        landings: vec![0; dials.len()],
+        crossings: vec![0; dials.len()],
+        all_zero: Vec::new(),
+    };
+
+    for rotation in rotations {
+        let click = Rotation {
+            direction: rotation.direction,
+            distance: 1,
+            line: rotation.line,
+        };
+        for input_click in 1..=u64::from(rotation.distance) {
+            for (i, geared) in dials.iter_mut().enumerate() {
+                for _ in 0..geared.ratio {
+                    report.crossings[i] += geared.dial.rotate_by_clicks(click).crossings;
+                }
+            }
+            if dials.iter().all(|geared| geared.dial.position() == 0) {
+                report.all_zero.push(AllZero {
+                    line: rotation.line,
+                    click: input_click,
+                });
+            }
+        }
+        for (i, geared) in dials.iter().enumerate() {
+            report.landings[i] += u64::from(geared.dial.position() == 0);
+        }
+    }
+    report
+}
+
+#[test]
+fn test_lock_matches_click_simulation() {
+    for seed in 0..2000 {
+        let mut rng = StdRng::seed_from_u64(seed);
+        // Small dials with shared factors, so that they often read zero together.
+        let dials = (0..rng.random_range(1..4))
+            .map(|_| {
+                let positions = rng.random_range(1..13);
+                GearedDial {
+                    dial: Dial::new(positions, rng.random_range(0..positions)),
+                    ratio: rng.random_range(0..6),
+                }
+            })
+            .collect();
+        let lock = Lock::new(dials);
+        let rotations: Vec<Rotation> = (1..=rng.random_range(0..15))
+            .map(|line| random_rotation(&mut rng, 12, line))
+            .collect();
+
+        assert_eq!(
+            lock.clone().run(&rotations),
+            simulate_lock(&lock, &rotations),
+            "seed {}: {:?} through {:?}",
+            seed,
+            lock,
+            rotations
+        );
+    }
+}
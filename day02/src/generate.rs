@@ -0,0 +1,24 @@
+//! Random puzzle inputs for property tests.
+
+use rand::{Rng, SeedableRng, rngs::StdRng};
+
+/// A line of `size` random ID ranges, reproducible from `seed`. Ranges are short enough to
+/// brute-force and often straddle a power of ten, where the number of digits changes.
+pub fn input(seed: u64, size: usize) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+
+    let ranges: Vec<String> = (0..size)
+        .map(|_| {
+            let start = if rng.random_bool(0.3) {
+                let boundary = 10u64.pow(rng.random_range(1..7));
+                boundary.saturating_sub(rng.random_range(0..500)).max(1)
+            } else {
+                rng.random_range(1..1_000_000)
+            };
+            let end = start + rng.random_range(0..200);
+            format!("{}-{}", start, end)
+        })
+        .collect();
+
+    ranges.join(",") + "\n"
+}
@@ -0,0 +1,173 @@
+use aoc_core::{ParseError, Part, Solution, Strategy, lines};
+
+#[cfg(feature = "generate")]
+pub mod generate;
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+
+    type Input = Vec<(i64, i64)>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(ranges: &Self::Input) -> Self::Answer1 {
+        part1(ranges)
+    }
+
+    fn part2(ranges: &Self::Input) -> Self::Answer2 {
+        part2(ranges)
+    }
+
+    fn strategies() -> Vec<Strategy<Self::Input>> {
+        vec![
+            Strategy::new(Part::Two, "brute", |ranges| part2(ranges).into()),
+            Strategy::new(Part::Two, "optimized", |ranges| {
+                part2_optimized(ranges).into()
+            }),
+        ]
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<(i64, i64)>, ParseError> {
+    let mut ranges = Vec::new();
+    for line in lines(input).filter(|line| !line.text.trim().is_empty()) {
+        for range in line.text.split(',').map(str::trim) {
+            let (start_str, end_str) = range
+                .split_once('-')
+                .ok_or_else(|| line.error(range, "a range `start-end`"))?;
+            let start = line.parse(start_str, "a range start")?;
+            let end = line.parse(end_str, "a range end")?;
+            ranges.push((start, end));
+        }
+    }
+
+    Ok(ranges)
+}
+
+pub fn part1(ranges: &[(i64, i64)]) -> i64 {
+    let mut total_count = 0;
+    for &(start, end) in ranges {
+        for num in start..=end {
+            let num_str = num.to_string();
+            let num_len = num_str.len();
+            if num_len % 2 != 0 {
+                continue;
+            }
+            let (left_part, right_part) = num_str.split_at(num_len / 2);
+            if left_part.ends_with(right_part) {
+                total_count += num;
+            }
+        }
+    }
+
+    total_count
+}
+
+pub fn part2(ranges: &[(i64, i64)]) -> i64 {
+    let mut total_count = 0;
+    for &(start, end) in ranges {
+        for num in start..=end {
+            let num_str = num.to_string();
+            let num_len = num_str.len();
+            for i in (1..=num_len / 2).rev() {
+                if num_len % i == 0 {
+                    let chunks = num_str
+                        .chars()
+                        .collect::<Vec<_>>()
+                        .chunks(i)
+                        .map(|chunk| chunk.iter().collect::<String>())
+                        .collect::<Vec<_>>();
+                    if chunks.windows(2).all(|w| w[0] == w[1]) {
+                        total_count += num;
+                        break;
+                    }
+                }
+            }
+        }
+    }
+
+    total_count
+}
+
+pub fn part2_optimized(ranges: &[(i64, i64)]) -> i64 {
+    use std::collections::HashSet;
+
+    let mut total_count = 0;
+    for &(start, end) in ranges {
+        let mut counted: HashSet<i64> = HashSet::new();
+
+        let start_digits = if start == 0 {
+            1
+        } else {
+            (start as f64).log10().floor() as usize + 1
+        };
+        let end_digits = (end as f64).log10().floor() as usize + 1;
+
+        for total_len in start_digits..=end_digits {
+            for chunk_size in 1..=total_len / 2 {
+                if total_len % chunk_size != 0 {
+                    continue;
+                }
+                let num_chunks = total_len / chunk_size;
+                if num_chunks < 2 {
+                    continue;
+                }
+
+                // Generate all base patterns of `chunk_size` digits
+                // Base pattern ranges from 10^(chunk_size-1) to 10^chunk_size - 1
+                // (or from 0 if chunk_size == 1, but we need leading zeros handled)
+                let base_start = if chunk_size == 1 {
+                    0
+                } else {
+                    10_i64.pow((chunk_size - 1) as u32)
+                };
+                let base_end = 10_i64.pow(chunk_size as u32);
+
+                for base in base_start..base_end {
+                    let pattern = format!("{:0>width$}", base, width = chunk_size);
+                    let num_str = pattern.repeat(num_chunks);
+
+                    // Skip if it has leading zeros (would be a different length number)
+                    if num_str.starts_with('0') {
+                        continue;
+                    }
+
+                    let num: i64 = num_str.parse().unwrap();
+
+                    // Check if the number is in range and not already counted
+                    if num >= start && num <= end && !counted.contains(&num) {
+                        counted.insert(num);
+                        total_count += num;
+                    }
+                }
+            }
+        }
+    }
+
+    total_count
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_core::example_tests!(Day02);
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            parse("11-22,95115").unwrap_err(),
+            ParseError::new(1, 7, "95115", "a range `start-end`")
+        );
+        assert_eq!(
+            parse("11-22,\n95-1x5").unwrap_err(),
+            ParseError::new(1, 7, "", "a range `start-end`")
+        );
+    }
+}
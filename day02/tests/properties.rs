@@ -0,0 +1,61 @@
+use day02::{generate, parse, part1, part2, part2_optimized};
+
+/// Whether the digits of `num` are one block repeated at least `min_repeats` times, and at
+/// most `max_repeats` times if given.
+fn is_repeated(num: i64, min_repeats: usize, max_repeats: Option<usize>) -> bool {
+    let digits = num.to_string().into_bytes();
+    (1..digits.len()).any(|block| {
+        let repeats = digits.len() / block;
+        digits.len().is_multiple_of(block)
+            && repeats >= min_repeats
+            && max_repeats.is_none_or(|max| repeats <= max)
+            && digits.chunks(block).all(|chunk| chunk == &digits[..block])
+    })
+}
+
+fn reference(ranges: &[(i64, i64)], min_repeats: usize, max_repeats: Option<usize>) -> i64 {
+    ranges
+        .iter()
+        .flat_map(|&(start, end)| start..=end)
+        .filter(|&num| is_repeated(num, min_repeats, max_repeats))
+        .sum()
+}
+
+#[test]
+fn test_parts_match_reference() {
+    for seed in 0..300 {
+        let input = generate::input(seed, 1 + seed as usize % 4);
+        let ranges = parse(&input).unwrap();
+
+        assert_eq!(
+            part1(&ranges),
+            reference(&ranges, 2, Some(2)),
+            "seed {}: {}",
+            seed,
+            input
+        );
+        assert_eq!(
+            part2(&ranges),
+            reference(&ranges, 2, None),
+            "seed {}: {}",
+            seed,
+            input
+        );
+    }
+}
+
+#[test]
+fn test_optimized_matches_brute_force() {
+    for seed in 0..1000 {
+        let input = generate::input(seed, 1 + seed as usize % 4);
+        let ranges = parse(&input).unwrap();
+
+        assert_eq!(
+            part2_optimized(&ranges),
+            part2(&ranges),
+            "seed {}: {}",
+            seed,
+            input
+        );
+    }
+}
@@ -0,0 +1,23 @@
+//! Random puzzle inputs for property tests.
+
+use rand::{Rng, SeedableRng, rngs::StdRng};
+
+use crate::MIN_BANK_LEN;
+
+/// `size` random banks of battery joltages, reproducible from `seed`.
+pub fn input(seed: u64, size: usize) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+
+    (0..size)
+        .map(|_| {
+            let len = rng.random_range(MIN_BANK_LEN..MIN_BANK_LEN + 12);
+            // A few distinct digits make ties, where picking the leftmost maximum matters.
+            let max_digit = rng.random_range(1..10);
+            let mut bank: String = (0..len)
+                .map(|_| char::from_digit(rng.random_range(1..=max_digit), 10).unwrap())
+                .collect();
+            bank.push('\n');
+            bank
+        })
+        .collect()
+}
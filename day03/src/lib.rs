@@ -0,0 +1,148 @@
+use aoc_core::{
+    ParseError, Solution, lines,
+    trace::{self, Event, Verbosity},
+};
+
+#[cfg(feature = "generate")]
+pub mod generate;
+
+/// Fewest batteries in a bank, so that part 2 can always turn on twelve of them.
+pub const MIN_BANK_LEN: usize = 12;
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+
+    type Input = Vec<Vec<u32>>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(banks: &Self::Input) -> Self::Answer1 {
+        part1(banks)
+    }
+
+    fn part2(banks: &Self::Input) -> Self::Answer2 {
+        part2(banks)
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<Vec<u32>>, ParseError> {
+    lines(input)
+        .map(|line| {
+            let bank = line
+                .text
+                .char_indices()
+                .map(|(i, str_digit)| {
+                    str_digit.to_digit(10).ok_or_else(|| {
+                        line.error(&line.text[i..i + str_digit.len_utf8()], "a digit")
+                    })
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            if bank.len() < MIN_BANK_LEN {
+                return Err(line.missing(format!("a bank of at least {} digits", MIN_BANK_LEN)));
+            }
+            Ok(bank)
+        })
+        .collect()
+}
+
+pub fn part1(banks: &[Vec<u32>]) -> u64 {
+    find_largest_number_of_n_digits(banks, 2)
+}
+
+pub fn part2(banks: &[Vec<u32>]) -> u64 {
+    find_largest_number_of_n_digits(banks, 12)
+}
+
+pub fn find_largest_number_of_n_digits(banks: &[Vec<u32>], n: usize) -> u64 {
+    let mut total_count = 0;
+    for (bank, digits) in banks.iter().enumerate() {
+        let mut selected_digits: Vec<u32> = Vec::new();
+        let mut current_start = 0;
+
+        while selected_digits.len() < n && current_start < digits.len() {
+            let remaining_digits_needed = n - selected_digits.len();
+            let end_index = (digits.len() + 1).saturating_sub(remaining_digits_needed);
+
+            if current_start >= end_index {
+                break;
+            }
+
+            let mut max_value = 0;
+            let mut max_index = current_start;
+            for (i, &digit) in digits
+                .iter()
+                .enumerate()
+                .take(end_index)
+                .skip(current_start)
+            {
+                if digit > max_value {
+                    max_value = digit;
+                    max_index = i;
+                }
+            }
+
+            trace::emit(Verbosity::Details, || {
+                Event::new("pick")
+                    .with("index", max_index)
+                    .with("digit", max_value)
+            });
+            selected_digits.push(max_value);
+            current_start = max_index + 1;
+        }
+
+        let mut result = 0u64;
+        for &digit in &selected_digits {
+            result = result * 10 + digit as u64;
+        }
+        trace::emit(Verbosity::Steps, || {
+            Event::new("bank")
+                .with("line", bank + 1)
+                .with("joltage", result)
+        });
+        total_count += result;
+    }
+
+    total_count
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../examples/example.txt");
+
+    aoc_core::example_tests!(Day03);
+
+    #[test]
+    fn test_explain() {
+        let banks = parse(EXAMPLE).unwrap();
+        let (_, events) = trace::explain(Verbosity::Details, || trace::capture(|| part1(&banks)));
+        let lines: Vec<String> = events.iter().take(3).map(Event::to_string).collect();
+        assert_eq!(
+            lines,
+            [
+                "    pick index=0 digit=9",
+                "    pick index=1 digit=8",
+                "  bank line=1 joltage=98"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            parse("987654321111111\n81111a111111119").unwrap_err(),
+            ParseError::new(2, 6, "a", "a digit")
+        );
+        assert_eq!(
+            parse("987654321111111\n8111").unwrap_err(),
+            ParseError::new(2, 5, "", "a bank of at least 12 digits")
+        );
+    }
+}
@@ -0,0 +1,50 @@
+use std::collections::HashMap;
+
+use day03::{generate, parse, part1, part2};
+
+/// The largest number formed by `n` digits of `digits[start..]` in order, trying every
+/// choice of the first digit.
+fn largest(digits: &[u32], start: usize, n: usize, memo: &mut HashMap<(usize, usize), u64>) -> u64 {
+    if n == 0 {
+        return 0;
+    }
+    if let Some(&best) = memo.get(&(start, n)) {
+        return best;
+    }
+    let best = (start..=digits.len() - n)
+        .map(|i| digits[i] as u64 * 10u64.pow(n as u32 - 1) + largest(digits, i + 1, n - 1, memo))
+        .max()
+        .unwrap();
+    memo.insert((start, n), best);
+    best
+}
+
+fn reference(banks: &[Vec<u32>], n: usize) -> u64 {
+    banks
+        .iter()
+        .map(|bank| largest(bank, 0, n, &mut HashMap::new()))
+        .sum()
+}
+
+#[test]
+fn test_parts_match_exhaustive_search() {
+    for seed in 0..2000 {
+        let input = generate::input(seed, 1 + seed as usize % 5);
+        let banks = parse(&input).unwrap();
+
+        assert_eq!(
+            part1(&banks),
+            reference(&banks, 2),
+            "seed {}:\n{}",
+            seed,
+            input
+        );
+        assert_eq!(
+            part2(&banks),
+            reference(&banks, 12),
+            "seed {}:\n{}",
+            seed,
+            input
+        );
+    }
+}
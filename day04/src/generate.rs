@@ -0,0 +1,19 @@
+//! Random puzzle inputs for property tests.
+
+use rand::{Rng, SeedableRng, rngs::StdRng};
+
+/// A random `size` x `size` grid of paper rolls, reproducible from `seed`.
+pub fn input(seed: u64, size: usize) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let density = rng.random_range(0.2..0.9);
+
+    (0..size)
+        .map(|_| {
+            let mut row: String = (0..size)
+                .map(|_| if rng.random_bool(density) { '@' } else { '.' })
+                .collect();
+            row.push('\n');
+            row
+        })
+        .collect()
+}
@@ -0,0 +1,166 @@
+use std::fmt;
+
+use aoc_core::{
+    Animation, Grid, ParseError, Pos, Solution,
+    trace::{self, Event, Verbosity},
+};
+
+#[cfg(feature = "generate")]
+pub mod generate;
+pub mod render;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Cell {
+    Paper,
+    Blank,
+}
+
+impl Cell {
+    pub fn from_char(ch: char) -> Option<Self> {
+        match ch {
+            '@' => Some(Cell::Paper),
+            '.' => Some(Cell::Blank),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Cell {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Cell::Paper => write!(f, "@"),
+            Cell::Blank => write!(f, "."),
+        }
+    }
+}
+
+pub const ADJACENT_PAPERS_CONSTRAINT: usize = 4;
+
+pub fn parse(input: &str) -> Result<Grid<Cell>, ParseError> {
+    Grid::parse(input, "`@` or `.`", Cell::from_char)
+}
+
+pub fn adjacent_papers(grid: &Grid<Cell>, pos: Pos) -> usize {
+    grid.neighbours8(pos)
+        .filter(|&neighbour| grid[neighbour] == Cell::Paper)
+        .count()
+}
+
+fn is_accessible(grid: &Grid<Cell>, pos: Pos) -> bool {
+    grid[pos] == Cell::Paper && adjacent_papers(grid, pos) < ADJACENT_PAPERS_CONSTRAINT
+}
+
+pub fn part1(grid: &Grid<Cell>) -> u64 {
+    grid.positions()
+        .filter(|&pos| is_accessible(grid, pos))
+        .count() as u64
+}
+
+/// Removes every roll accessible before the wave, so removals within it do not open up
+/// further rolls until the next one, and returns where they were in scan order.
+fn remove_wave(grid: &mut Grid<Cell>) -> Vec<Pos> {
+    let accessible: Vec<Pos> = grid
+        .positions()
+        .filter(|&pos| is_accessible(grid, pos))
+        .collect();
+    for &pos in &accessible {
+        grid[pos] = Cell::Blank;
+    }
+    accessible
+}
+
+pub fn part2(grid: &mut Grid<Cell>) -> u64 {
+    let mut total_count = 0;
+
+    for wave in 1.. {
+        let accessible = remove_wave(grid);
+        if accessible.is_empty() {
+            break;
+        }
+        for &pos in &accessible {
+            trace::emit(Verbosity::Details, || {
+                Event::new("remove").with("cell", pos)
+            });
+        }
+        trace::emit(Verbosity::Steps, || {
+            Event::new("wave")
+                .with("wave", wave)
+                .with("removed", accessible.len())
+        });
+        total_count += accessible.len() as u64;
+    }
+
+    total_count
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+
+    type Input = Grid<Cell>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(grid: &Self::Input) -> Self::Answer1 {
+        part1(grid)
+    }
+
+    fn part2(grid: &Self::Input) -> Self::Answer2 {
+        part2(&mut grid.clone())
+    }
+
+    fn animate(grid: &Self::Input) -> Option<Animation> {
+        Some(render::animate(grid))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_core::example_tests!(Day04);
+
+    const EXAMPLE: &str = include_str!("../examples/example.txt");
+
+    #[test]
+    fn test_explain() {
+        let grid = parse(EXAMPLE).unwrap();
+        let (_, events) = trace::explain(Verbosity::Steps, || {
+            trace::capture(|| part2(&mut grid.clone()))
+        });
+        let lines: Vec<String> = events.iter().map(Event::to_string).collect();
+        assert_eq!(
+            lines,
+            [
+                "  wave wave=1 removed=13",
+                "  wave wave=2 removed=12",
+                "  wave wave=3 removed=7",
+                "  wave wave=4 removed=5",
+                "  wave wave=5 removed=2",
+                "  wave wave=6 removed=1",
+                "  wave wave=7 removed=1",
+                "  wave wave=8 removed=1",
+                "  wave wave=9 removed=1",
+            ]
+        );
+        // One wave per animation frame, bar the final still one.
+        assert_eq!(lines.len(), render::animate(&grid).frames.len() - 1);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            parse("..@@\n.#@.").err(),
+            Some(ParseError::new(2, 2, "#", "`@` or `.`"))
+        );
+        assert_eq!(
+            parse("..@@\n.@.").err(),
+            Some(ParseError::new(2, 1, ".@.", "a row of 4 cells"))
+        );
+    }
+}
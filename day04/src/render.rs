@@ -0,0 +1,83 @@
+use aoc_core::{
+    Animation, Frame, Grid,
+    render::{Paint, Pixel, Rgb},
+};
+
+use crate::{Cell, remove_wave};
+
+const PAPER: Rgb = Rgb(230, 230, 220);
+const BLANK: Rgb = Rgb(40, 40, 48);
+const REMOVED: Rgb = Rgb(220, 60, 50);
+
+impl Paint for Cell {
+    fn pixel(&self) -> Pixel {
+        match self {
+            Cell::Paper => Pixel::new('@', PAPER),
+            Cell::Blank => Pixel::new('.', BLANK),
+        }
+    }
+}
+
+/// The grid before each of `part2`'s waves of removals, with the rolls the wave takes away
+/// in red, and a last frame of what is left.
+pub fn animate(grid: &Grid<Cell>) -> Animation {
+    let mut grid = grid.clone();
+    let mut animation = Animation::default();
+
+    loop {
+        let wave = remove_wave(&mut grid);
+        if wave.is_empty() {
+            break;
+        }
+        let mut removed = Grid::new(grid.width(), grid.height(), false);
+        for pos in wave {
+            removed[pos] = true;
+        }
+        // The removed rolls are painted over, so the grid after the wave draws the one
+        // before it.
+        animation.push(Frame::new(&grid, |pos, cell| {
+            if removed[pos] {
+                Pixel::new('x', REMOVED)
+            } else {
+                cell.pixel()
+            }
+        }));
+    }
+
+    animation.push(Frame::of(&grid));
+    animation
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, part1, part2};
+
+    const EXAMPLE: &str = include_str!("../examples/example.txt");
+
+    fn count(frame: &Frame, glyph: char) -> usize {
+        frame
+            .pixels
+            .iter()
+            .filter(|(_, pixel)| pixel.glyph == glyph)
+            .count()
+    }
+
+    #[test]
+    fn test_animate() {
+        let grid = parse(EXAMPLE).unwrap();
+        let animation = animate(&grid);
+        let frames = &animation.frames;
+
+        assert_eq!(count(&frames[0], 'x') as u64, part1(&grid));
+        let removed: usize = frames.iter().map(|frame| count(frame, 'x')).sum();
+        assert_eq!(removed as u64, part2(&mut grid.clone()));
+
+        let last = frames.last().unwrap();
+        assert_eq!(count(last, 'x'), 0);
+        assert_eq!(
+            count(last, '@'),
+            count(&frames[0], '@') + count(&frames[0], 'x') - removed
+        );
+    }
+}
@@ -0,0 +1,23 @@
+use aoc_core::Pos;
+use day04::{Cell, adjacent_papers, parse, part1, part2};
+
+#[test]
+fn test_grid() {
+    let grid = parse("@@.\n@@@\n..@").unwrap();
+
+    assert_eq!(grid.height(), 3);
+    assert_eq!(grid.width(), 3);
+    assert_eq!(grid[Pos::new(1, 2)], Cell::Paper);
+    assert_eq!(grid[Pos::new(0, 2)], Cell::Blank);
+    assert_eq!(grid.get(Pos::new(3, 0)), None);
+    assert_eq!(adjacent_papers(&grid, Pos::new(1, 1)), 5);
+    assert_eq!(grid.to_string(), "@@.\n@@@\n..@");
+}
+
+#[test]
+fn test_parts_from_another_crate() {
+    let mut grid = parse("@@@\n@@@\n@@@").unwrap();
+
+    assert_eq!(part1(&grid), 4);
+    assert_eq!(part2(&mut grid), 9);
+}
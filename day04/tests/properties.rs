@@ -0,0 +1,65 @@
+use day04::{generate, parse, part1, part2};
+
+/// Rolls with fewer than four rolls among their eight neighbours.
+fn accessible(rows: &[Vec<bool>]) -> Vec<(usize, usize)> {
+    let mut found = Vec::new();
+    for (r, row) in rows.iter().enumerate() {
+        for (c, &paper) in row.iter().enumerate() {
+            let mut neighbours = 0;
+            for nr in r.saturating_sub(1)..=r + 1 {
+                for nc in c.saturating_sub(1)..=c + 1 {
+                    if (nr, nc) != (r, c) && rows.get(nr).and_then(|row| row.get(nc)) == Some(&true)
+                    {
+                        neighbours += 1;
+                    }
+                }
+            }
+            if paper && neighbours < 4 {
+                found.push((r, c));
+            }
+        }
+    }
+    found
+}
+
+/// Removes every accessible roll at once, wave after wave, until none is left.
+fn remove_in_waves(mut rows: Vec<Vec<bool>>) -> u64 {
+    let mut removed = 0;
+    loop {
+        let wave = accessible(&rows);
+        if wave.is_empty() {
+            return removed;
+        }
+        for (r, c) in wave {
+            rows[r][c] = false;
+            removed += 1;
+        }
+    }
+}
+
+#[test]
+fn test_parts_match_wave_simulation() {
+    for seed in 0..1000 {
+        let input = generate::input(seed, 1 + seed as usize % 12);
+        let rows: Vec<Vec<bool>> = input
+            .lines()
+            .map(|line| line.chars().map(|c| c == '@').collect())
+            .collect();
+        let mut grid = parse(&input).unwrap();
+
+        assert_eq!(
+            part1(&grid),
+            accessible(&rows).len() as u64,
+            "seed {}:\n{}",
+            seed,
+            input
+        );
+        assert_eq!(
+            part2(&mut grid),
+            remove_in_waves(rows),
+            "seed {}:\n{}",
+            seed,
+            input
+        );
+    }
+}
@@ -0,0 +1,22 @@
+//! Random puzzle inputs for property tests.
+
+use rand::{Rng, SeedableRng, rngs::StdRng};
+
+/// `size` random fresh-ID ranges followed by `size` available IDs, reproducible from
+/// `seed`. IDs are kept small so ranges often overlap, nest or touch.
+pub fn input(seed: u64, size: usize) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let max_id = 10 * size as u64 + 10;
+
+    let mut out = String::new();
+    for _ in 0..size {
+        let start = rng.random_range(0..=max_id);
+        let end = start + rng.random_range(0..=max_id / 4);
+        out.push_str(&format!("{}-{}\n", start, end));
+    }
+    out.push('\n');
+    for _ in 0..size {
+        out.push_str(&format!("{}\n", rng.random_range(0..=max_id + max_id / 4)));
+    }
+    out
+}
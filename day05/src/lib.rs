@@ -0,0 +1,179 @@
+use std::collections::HashSet;
+
+use aoc_core::{
+    ParseError, Solution, lines,
+    trace::{self, Event, Verbosity},
+};
+
+#[cfg(feature = "generate")]
+pub mod generate;
+
+#[derive(Debug, Clone)]
+pub struct Inventory {
+    pub ranges: Vec<(u64, u64)>,
+    pub ids: Vec<u64>,
+}
+
+impl Inventory {
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let mut ranges = Vec::new();
+        let mut ids = Vec::new();
+
+        let mut ranges_processed = false;
+        for line in lines(input) {
+            let text = line.text.trim();
+            if !ranges_processed && !line.text.is_empty() {
+                let (start_text, end_text) = text
+                    .split_once('-')
+                    .ok_or_else(|| line.error(text, "a range `start-end`"))?;
+                let start = line.parse(start_text, "a range start")?;
+                let end = line.parse(end_text, "a range end")?;
+                if end < start {
+                    return Err(line.error(end_text, format!("a range end of at least {}", start)));
+                }
+                ranges.push((start, end));
+            } else if line.text.is_empty() {
+                ranges_processed = true;
+            } else {
+                ids.push(line.parse(text, "an ingredient ID")?);
+            }
+        }
+
+        Ok(Inventory { ranges, ids })
+    }
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+
+    type Input = Inventory;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Inventory::parse(input)
+    }
+
+    fn part1(inventory: &Self::Input) -> Self::Answer1 {
+        part1(inventory)
+    }
+
+    fn part2(inventory: &Self::Input) -> Self::Answer2 {
+        part2(inventory)
+    }
+}
+
+pub fn part1(inventory: &Inventory) -> u64 {
+    let mut ids = inventory.ids.clone();
+
+    let mut total_count = 0;
+    for &(start, end) in &inventory.ranges {
+        let mut i = 0;
+        while i < ids.len() {
+            if ids[i] >= start && ids[i] <= end {
+                total_count += 1;
+                ids.remove(i);
+            } else {
+                i += 1;
+            }
+        }
+    }
+
+    total_count
+}
+
+pub fn part2(inventory: &Inventory) -> u64 {
+    let mut new_ranges = Vec::new();
+
+    for &(mut start, mut end) in &inventory.ranges {
+        let mut overlaps_indices = HashSet::new();
+
+        for (i, (ns, ne)) in new_ranges.iter().enumerate() {
+            if (start >= *ns && start <= *ne)
+                || (end >= *ns && end <= *ne)
+                || (*ns >= start && *ns <= end)
+                || (*ne >= start && *ne <= end)
+            {
+                overlaps_indices.insert(i);
+            }
+        }
+
+        if overlaps_indices.is_empty() {
+            new_ranges.push((start, end));
+        } else {
+            for &i in overlaps_indices.iter() {
+                let (ns, ne) = new_ranges[i];
+                if ns < start {
+                    start = ns;
+                }
+                if ne > end {
+                    end = ne;
+                }
+            }
+
+            let mut overlaps_indices_vec: Vec<usize> = overlaps_indices.into_iter().collect();
+            overlaps_indices_vec.sort_unstable_by(|a, b| b.cmp(a));
+            let merged = overlaps_indices_vec.len();
+            for i in overlaps_indices_vec {
+                let (ns, ne) = new_ranges.remove(i);
+                trace::emit(Verbosity::Details, || {
+                    Event::new("absorb").with("range", format!("{}-{}", ns, ne))
+                });
+            }
+
+            trace::emit(Verbosity::Steps, || {
+                Event::new("merge")
+                    .with("ranges", merged + 1)
+                    .with("into", format!("{}-{}", start, end))
+            });
+            new_ranges.push((start, end));
+        }
+    }
+
+    trace::emit(Verbosity::Summary, || {
+        Event::new("ranges")
+            .with("given", inventory.ranges.len())
+            .with("merged", new_ranges.len())
+    });
+    new_ranges
+        .iter()
+        .fold(0, |acc, (start, end)| acc + (end - start + 1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../examples/example.txt");
+
+    aoc_core::example_tests!(Day05);
+
+    #[test]
+    fn test_explain() {
+        let inventory = Inventory::parse(EXAMPLE).unwrap();
+        let (_, events) = trace::explain(Verbosity::Steps, || trace::capture(|| part2(&inventory)));
+        let lines: Vec<String> = events.iter().map(Event::to_string).collect();
+        assert_eq!(
+            lines,
+            ["  merge ranges=3 into=10-20", "ranges given=4 merged=2"]
+        );
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            Inventory::parse("3-5\n10:14").unwrap_err(),
+            ParseError::new(2, 1, "10:14", "a range `start-end`")
+        );
+        assert_eq!(
+            Inventory::parse("3-5\n10-9").unwrap_err(),
+            ParseError::new(2, 4, "9", "a range end of at least 10")
+        );
+        assert_eq!(
+            Inventory::parse("3-5\n\n1\n5x").unwrap_err(),
+            ParseError::new(4, 1, "5x", "an ingredient ID")
+        );
+    }
+}
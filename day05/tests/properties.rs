@@ -0,0 +1,33 @@
+use std::collections::BTreeSet;
+
+use day05::{Inventory, generate, part1, part2};
+
+#[test]
+fn test_parts_match_id_by_id_reference() {
+    for seed in 0..2000 {
+        let input = generate::input(seed, 1 + seed as usize % 15);
+        let inventory = Inventory::parse(&input).unwrap();
+
+        let fresh: BTreeSet<u64> = inventory
+            .ranges
+            .iter()
+            .flat_map(|&(start, end)| start..=end)
+            .collect();
+        let available_fresh = inventory.ids.iter().filter(|id| fresh.contains(id)).count() as u64;
+
+        assert_eq!(
+            part1(&inventory),
+            available_fresh,
+            "seed {}:\n{}",
+            seed,
+            input
+        );
+        assert_eq!(
+            part2(&inventory),
+            fresh.len() as u64,
+            "seed {}:\n{}",
+            seed,
+            input
+        );
+    }
+}
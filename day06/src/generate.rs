@@ -0,0 +1,49 @@
+//! Random puzzle inputs for property tests.
+
+use rand::{Rng, SeedableRng, rngs::StdRng};
+
+/// A worksheet of `size` random problems, reproducible from `seed`. Each problem is a
+/// block of columns with one number per row, all aligned left or all right, and its
+/// operator under the block's first column; blocks are separated by a column of spaces.
+///
+/// As in real inputs, the digits of each column are contiguous: number lengths only grow
+/// or only shrink down the rows.
+pub fn input(seed: u64, size: usize) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let rows = rng.random_range(2..5);
+
+    let mut lines = vec![String::new(); rows + 1];
+    for problem in 0..size {
+        if problem > 0 {
+            for line in &mut lines {
+                line.push(' ');
+            }
+        }
+
+        let width = rng.random_range(1..5);
+        let mut lengths: Vec<usize> = (0..rows).map(|_| rng.random_range(1..=width)).collect();
+        // One number spans the whole block so that no column inside it is blank.
+        lengths[0] = width;
+        lengths.sort_unstable();
+        if rng.random_bool(0.5) {
+            lengths.reverse();
+        }
+
+        let left = rng.random_bool(0.5);
+        for (line, len) in lines.iter_mut().zip(lengths) {
+            let number: String = (0..len)
+                .map(|_| char::from_digit(rng.random_range(1..10), 10).unwrap())
+                .collect();
+            if left {
+                line.push_str(&format!("{:<width$}", number));
+            } else {
+                line.push_str(&format!("{:>width$}", number));
+            }
+        }
+
+        let operator = if rng.random_bool(0.5) { '+' } else { '*' };
+        lines[rows].push_str(&format!("{:<width$}", operator));
+    }
+
+    lines.join("\n") + "\n"
+}
@@ -0,0 +1,211 @@
+use aoc_core::{Line, ParseError, Solution, lines};
+
+#[cfg(feature = "generate")]
+pub mod generate;
+
+#[derive(Debug, Clone)]
+pub struct Worksheet {
+    pub lines: Vec<String>,
+}
+
+impl Worksheet {
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let all_lines = lines(input).collect::<Vec<_>>();
+        let Some((operators, numbers)) = all_lines.split_last() else {
+            return Err(ParseError::new(1, 1, "", "a row of numbers"));
+        };
+
+        check_chars(
+            operators,
+            |c| c == '+' || c == '*' || c == ' ',
+            "an operator `+` or `*`",
+        )?;
+        let problems = operators.text.split_whitespace().count();
+
+        for line in numbers {
+            check_chars(line, |c| c.is_ascii_digit() || c == ' ', "a digit or space")?;
+
+            // Every row has one number per problem, and so one per operator.
+            let tokens: Vec<&str> = line.text.split_whitespace().collect();
+            for token in &tokens {
+                line.parse::<u64>(token, "a number")?;
+            }
+            let expected = format!("{} numbers, one per operator", problems);
+            if let Some(extra) = tokens.get(problems) {
+                return Err(line.error(extra, expected));
+            }
+            if tokens.len() < problems {
+                return Err(line.missing(expected));
+            }
+        }
+
+        Ok(Worksheet {
+            lines: all_lines.iter().map(|line| line.text.to_string()).collect(),
+        })
+    }
+}
+
+fn check_chars(
+    line: &Line,
+    valid: impl Fn(char) -> bool,
+    expected: &str,
+) -> Result<(), ParseError> {
+    match line.text.char_indices().find(|&(_, c)| !valid(c)) {
+        Some((i, c)) => Err(line.error(&line.text[i..i + c.len_utf8()], expected)),
+        None => Ok(()),
+    }
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+
+    type Input = Worksheet;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Worksheet::parse(input)
+    }
+
+    fn part1(worksheet: &Self::Input) -> Self::Answer1 {
+        part1(worksheet)
+    }
+
+    fn part2(worksheet: &Self::Input) -> Self::Answer2 {
+        part2(worksheet)
+    }
+}
+
+pub fn part1(worksheet: &Worksheet) -> u64 {
+    let mut signs = Vec::new();
+    let mut nums = Vec::new();
+    worksheet.lines.iter().for_each(|line| {
+        line.split_ascii_whitespace()
+            .enumerate()
+            .for_each(|(i, num)| {
+                match num.trim().parse::<u64>() {
+                    Ok(v) => {
+                        if nums.len() <= i {
+                            nums.push(Vec::new());
+                        }
+                        nums[i].push(v);
+                    }
+                    Err(_e) => signs.push(num.trim()),
+                };
+            });
+    });
+
+    nums.iter().enumerate().fold(0u64, |acc, (i, col)| {
+        let sign = signs.get(i).unwrap();
+        let col_result = match *sign {
+            "+" => col.iter().sum::<u64>(),
+            "*" => col.iter().product::<u64>(),
+            _ => acc,
+        };
+        acc + col_result
+    })
+}
+
+pub fn part2(worksheet: &Worksheet) -> u64 {
+    let lines = &worksheet.lines;
+    let ncols = lines[0].len();
+    let mut results = Vec::new();
+
+    let mut current_numbers = Vec::new();
+    let mut current_sign = None;
+
+    let mut col = 0;
+    while col < ncols {
+        let mut is_empty = true;
+        for line in lines {
+            let c = line.chars().nth(col).unwrap_or(' ');
+            if c != ' ' {
+                is_empty = false;
+                break;
+            }
+        }
+
+        if is_empty {
+            if let Some(sign) = current_sign
+                && !current_numbers.is_empty()
+            {
+                let res = match sign {
+                    '+' => current_numbers.iter().sum::<u64>(),
+                    '*' => current_numbers.iter().product::<u64>(),
+                    _ => 0,
+                };
+                results.push(res);
+            }
+            current_numbers.clear();
+            current_sign = None;
+            col += 1;
+            continue;
+        }
+
+        let mut col_digits = String::new();
+        let mut sign = None;
+        for line in lines {
+            let c = line.chars().nth(col).unwrap_or(' ');
+            if c == '+' || c == '*' {
+                sign = Some(c);
+            } else if c.is_ascii_digit() {
+                col_digits.push(c);
+            } else if !col_digits.is_empty() {
+                // End of number in this column
+                if let Ok(num) = col_digits.parse::<u64>() {
+                    current_numbers.push(num);
+                }
+                col_digits.clear();
+            }
+        }
+        if !col_digits.is_empty()
+            && let Ok(num) = col_digits.parse::<u64>()
+        {
+            current_numbers.push(num);
+        }
+        if sign.is_some() {
+            current_sign = sign;
+        }
+        col += 1;
+    }
+    if let Some(sign) = current_sign
+        && !current_numbers.is_empty()
+    {
+        let res = match sign {
+            '+' => current_numbers.iter().sum::<u64>(),
+            '*' => current_numbers.iter().product::<u64>(),
+            _ => 0,
+        };
+        results.push(res);
+    }
+    results.iter().sum::<u64>()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_core::example_tests!(Day06);
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            Worksheet::parse("123 328\n 4,5 64\n*   +").unwrap_err(),
+            ParseError::new(2, 3, ",", "a digit or space")
+        );
+        assert_eq!(
+            Worksheet::parse("123 328 4\n*   +").unwrap_err(),
+            ParseError::new(1, 9, "4", "2 numbers, one per operator")
+        );
+        assert_eq!(
+            Worksheet::parse("123\n*   +").unwrap_err(),
+            ParseError::new(1, 4, "", "2 numbers, one per operator")
+        );
+        assert_eq!(
+            Worksheet::parse("123 328\n*   -").unwrap_err(),
+            ParseError::new(2, 5, "-", "an operator `+` or `*`")
+        );
+    }
+}
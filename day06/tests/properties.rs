@@ -0,0 +1,62 @@
+use day06::{Worksheet, generate, part1, part2};
+
+fn evaluate(operator: char, numbers: impl Iterator<Item = u64>) -> u64 {
+    match operator {
+        '+' => numbers.sum(),
+        _ => numbers.product(),
+    }
+}
+
+/// Splits the worksheet into problems at blank columns, then reads each problem's
+/// numbers row by row (part 1) and column by column (part 2).
+fn reference(input: &str) -> (u64, u64) {
+    let rows: Vec<Vec<char>> = input.lines().map(|line| line.chars().collect()).collect();
+    let width = rows.iter().map(Vec::len).max().unwrap();
+    let cell = |row: usize, col: usize| rows[row].get(col).copied().unwrap_or(' ');
+    let (operators, numbers) = (rows.len() - 1, 0..rows.len() - 1);
+
+    let mut blocks = Vec::new();
+    let mut start = 0;
+    for col in 0..=width {
+        if col == width || (0..rows.len()).all(|row| cell(row, col) == ' ') {
+            if col > start {
+                blocks.push(start..col);
+            }
+            start = col + 1;
+        }
+    }
+
+    let mut totals = (0, 0);
+    for block in blocks {
+        let operator = block
+            .clone()
+            .map(|col| cell(operators, col))
+            .find(|&c| c != ' ');
+        let operator = operator.unwrap();
+
+        let by_row = numbers.clone().map(|row| {
+            let text: String = block.clone().map(|col| cell(row, col)).collect();
+            text.trim().parse::<u64>().unwrap()
+        });
+        let by_col = block.clone().map(|col| {
+            let text: String = numbers.clone().map(|row| cell(row, col)).collect();
+            text.trim().parse::<u64>().unwrap()
+        });
+
+        totals.0 += evaluate(operator, by_row);
+        totals.1 += evaluate(operator, by_col);
+    }
+    totals
+}
+
+#[test]
+fn test_parts_match_column_reference() {
+    for seed in 0..2000 {
+        let input = generate::input(seed, 1 + seed as usize % 10);
+        let worksheet = Worksheet::parse(&input).unwrap();
+        let (expected1, expected2) = reference(&input);
+
+        assert_eq!(part1(&worksheet), expected1, "seed {}:\n{}", seed, input);
+        assert_eq!(part2(&worksheet), expected2, "seed {}:\n{}", seed, input);
+    }
+}
@@ -0,0 +1,32 @@
+//! Random puzzle inputs for property tests.
+
+use rand::{Rng, SeedableRng, rngs::StdRng};
+
+/// A manifold with `size` rows of splitters, reproducible from `seed`. Like real inputs,
+/// the start is on the top row, splitters sit on every other row away from the edges,
+/// and no two splitters are side by side.
+pub fn input(seed: u64, size: usize) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let width = rng.random_range(3..20);
+    let density = rng.random_range(0.1..0.6);
+
+    let mut rows = Vec::new();
+    let mut top = vec!['.'; width];
+    top[rng.random_range(0..width)] = 'S';
+    rows.push(top);
+
+    for _ in 0..size {
+        rows.push(vec!['.'; width]);
+        let mut row = vec!['.'; width];
+        for col in 1..width - 1 {
+            if row[col - 1] != '^' && rng.random_bool(density) {
+                row[col] = '^';
+            }
+        }
+        rows.push(row);
+    }
+    rows.push(vec!['.'; width]);
+
+    let lines: Vec<String> = rows.iter().map(|row| row.iter().collect()).collect();
+    lines.join("\n") + "\n"
+}
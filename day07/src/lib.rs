@@ -0,0 +1,209 @@
+use std::{collections::HashMap, fmt};
+
+use aoc_core::{Animation, Cancel, Grid, ParseError, Pos, Solution, TimedOut};
+
+#[cfg(feature = "generate")]
+pub mod generate;
+pub mod render;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Cell {
+    StartingPoint,
+    Beam,
+    Blank,
+    Splitter,
+}
+
+impl Cell {
+    /// Cells that can appear in the puzzle input; beams only appear while solving.
+    pub fn from_char(ch: char) -> Option<Self> {
+        match ch {
+            'S' => Some(Cell::StartingPoint),
+            '.' => Some(Cell::Blank),
+            '^' => Some(Cell::Splitter),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Cell {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let ch = match self {
+            Cell::StartingPoint => 'S',
+            Cell::Beam => '|',
+            Cell::Blank => '.',
+            Cell::Splitter => '^',
+        };
+        write!(f, "{}", ch)
+    }
+}
+
+pub fn parse(input: &str) -> Result<Grid<Cell>, ParseError> {
+    Grid::parse(input, "`S`, `.` or `^`", Cell::from_char)
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+
+    type Input = Grid<Cell>;
+    type Answer1 = u64;
+    type Answer2 = Result<u64, TimedOut>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(grid: &Self::Input) -> Self::Answer1 {
+        part1(&mut grid.clone())
+    }
+
+    fn part2(grid: &Self::Input) -> Self::Answer2 {
+        part2(grid, &Cancel::current())
+    }
+
+    fn animate(grid: &Self::Input) -> Option<Animation> {
+        Some(render::animate(grid))
+    }
+}
+
+pub fn part1(grid: &mut Grid<Cell>) -> u64 {
+    (1..grid.height()).map(|row| propagate(grid, row)).sum()
+}
+
+/// Extends the beams of the row above into `row`, and returns how many splitters they hit.
+pub fn propagate(grid: &mut Grid<Cell>, row: usize) -> u64 {
+    let mut splits = 0;
+
+    for col in 0..grid.width() {
+        let pos = Pos::new(row, col);
+        let current = grid[pos];
+        let above = grid[Pos::new(row - 1, col)];
+
+        if current == Cell::Blank && (above == Cell::StartingPoint || above == Cell::Beam) {
+            grid[pos] = Cell::Beam;
+        }
+
+        if current == Cell::Splitter && above == Cell::Beam {
+            splits += 1;
+
+            // Only a splitter that a beam reaches sends beams sideways.
+            for side in [pos.offset(0, -1), pos.offset(0, 1)].into_iter().flatten() {
+                if grid.get(side) == Some(&Cell::Blank) {
+                    grid[side] = Cell::Beam;
+                }
+            }
+        }
+    }
+
+    splits
+}
+
+pub fn part2(grid: &Grid<Cell>, cancel: &Cancel) -> Result<u64, TimedOut> {
+    let start_col = grid
+        .row(0)
+        .iter()
+        .position(|&cell| cell == Cell::StartingPoint)
+        .unwrap_or(0);
+
+    let mut cache = HashMap::new();
+
+    count_paths(grid, Pos::new(1, start_col), &mut cache, cancel)
+}
+
+/// Number of distinct beam paths starting at `pos` and travelling down.
+pub fn count_paths(
+    grid: &Grid<Cell>,
+    pos: Pos,
+    cache: &mut HashMap<Pos, u64>,
+    cancel: &Cancel,
+) -> Result<u64, TimedOut> {
+    if let Some(&cached) = cache.get(&pos) {
+        return Ok(cached);
+    }
+    cancel.check()?;
+
+    for row in pos.row..grid.height() {
+        if grid.get(Pos::new(row, pos.col)) == Some(&Cell::Splitter) {
+            let paths = split_paths(grid, row, pos.col, cache, cancel)?;
+            cache.insert(pos, paths);
+            return Ok(paths);
+        }
+    }
+
+    cache.insert(pos, 1);
+    Ok(1)
+}
+
+fn split_paths(
+    grid: &Grid<Cell>,
+    splitter_row: usize,
+    col: usize,
+    cache: &mut HashMap<Pos, u64>,
+    cancel: &Cancel,
+) -> Result<u64, TimedOut> {
+    let mut paths = 0;
+
+    if col > 0 {
+        paths += count_paths_from_split(grid, Pos::new(splitter_row, col - 1), cache, cancel)?;
+    }
+
+    if col + 1 < grid.width() {
+        paths += count_paths_from_split(grid, Pos::new(splitter_row, col + 1), cache, cancel)?;
+    }
+
+    Ok(paths)
+}
+
+/// Paths of a beam leaving the splitter in `beam.row` sideways into column `beam.col`.
+fn count_paths_from_split(
+    grid: &Grid<Cell>,
+    beam: Pos,
+    cache: &mut HashMap<Pos, u64>,
+    cancel: &Cancel,
+) -> Result<u64, TimedOut> {
+    if let Some(&cached) = cache.get(&beam) {
+        return Ok(cached);
+    }
+    cancel.check()?;
+
+    for row in (beam.row + 1)..grid.height() {
+        if grid.get(Pos::new(row, beam.col)) == Some(&Cell::Splitter) {
+            let paths = split_paths(grid, row, beam.col, cache, cancel)?;
+            cache.insert(beam, paths);
+            return Ok(paths);
+        }
+    }
+
+    cache.insert(beam, 1);
+    Ok(1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../examples/example.txt");
+
+    aoc_core::example_tests!(Day07);
+
+    #[test]
+    fn test_part2_cancelled() {
+        let cancel = Cancel::new();
+        cancel.cancel();
+        assert_eq!(part2(&parse(EXAMPLE).unwrap(), &cancel), Err(TimedOut));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            parse("..S..\n..v..").err(),
+            Some(ParseError::new(2, 3, "v", "`S`, `.` or `^`"))
+        );
+        assert_eq!(
+            parse("..S..\n..^...").err(),
+            Some(ParseError::new(2, 1, "..^...", "a row of 5 cells"))
+        );
+    }
+}
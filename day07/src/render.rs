@@ -0,0 +1,50 @@
+use aoc_core::{
+    Animation, Frame, Grid,
+    render::{Paint, Pixel, Rgb},
+};
+
+use crate::{Cell, propagate};
+
+impl Paint for Cell {
+    fn pixel(&self) -> Pixel {
+        match self {
+            Cell::StartingPoint => Pixel::new('S', Rgb(250, 200, 60)),
+            Cell::Beam => Pixel::new('|', Rgb(90, 200, 255)),
+            Cell::Blank => Pixel::new('.', Rgb(30, 30, 40)),
+            Cell::Splitter => Pixel::new('^', Rgb(160, 160, 170)),
+        }
+    }
+}
+
+/// The manifold as the beams travel down it, one frame per row.
+pub fn animate(grid: &Grid<Cell>) -> Animation {
+    let mut grid = grid.clone();
+    let mut animation = Animation::default();
+    animation.push(Frame::of(&grid));
+
+    for row in 1..grid.height() {
+        propagate(&mut grid, row);
+        animation.push(Frame::of(&grid));
+    }
+
+    animation
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, part1};
+
+    const EXAMPLE: &str = include_str!("../examples/example.txt");
+
+    #[test]
+    fn test_animate() {
+        let mut grid = parse(EXAMPLE).unwrap();
+        let animation = animate(&grid);
+        assert_eq!(animation.frames.len(), grid.height());
+        assert_eq!(animation.frames[0], Frame::of(&grid));
+
+        part1(&mut grid);
+        assert_eq!(animation.frames.last(), Some(&Frame::of(&grid)));
+    }
+}
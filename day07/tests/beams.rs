@@ -0,0 +1,9 @@
+use day07::{parse, part1};
+
+#[test]
+fn test_unreached_splitters_send_no_beams() {
+    // The beam from `S` runs straight down; the splitter off to the side is never
+    // reached, so it must not send a beam on to the second splitter.
+    let mut grid = parse(".S...\n.....\n...^.\n.....\n....^").unwrap();
+    assert_eq!(part1(&mut grid), 0);
+}
@@ -0,0 +1,58 @@
+use aoc_core::Cancel;
+use day07::{Cell, generate, parse, part1, part2};
+
+/// Moves the beams down one row at a time, counting how many splitters they hit
+/// (part 1) and how many timelines reach each column (part 2).
+fn reference(input: &str) -> (u64, u64) {
+    let grid = parse(input).unwrap();
+    let mut timelines = vec![0u64; grid.width()];
+    let start = grid.row(0).iter().position(|&c| c == Cell::StartingPoint);
+    timelines[start.unwrap()] = 1;
+
+    let mut splits = 0;
+    for row in grid.rows().skip(1) {
+        let mut next = vec![0u64; grid.width()];
+        for (col, &count) in timelines.iter().enumerate() {
+            if count == 0 {
+                continue;
+            }
+            if row[col] == Cell::Splitter {
+                splits += 1;
+                if col > 0 {
+                    next[col - 1] += count;
+                }
+                if col + 1 < row.len() {
+                    next[col + 1] += count;
+                }
+            } else {
+                next[col] += count;
+            }
+        }
+        timelines = next;
+    }
+
+    (splits, timelines.iter().sum())
+}
+
+#[test]
+fn test_parts_match_beam_simulation() {
+    for seed in 0..2000 {
+        let input = generate::input(seed, 1 + seed as usize % 12);
+        let (expected1, expected2) = reference(&input);
+
+        assert_eq!(
+            part1(&mut parse(&input).unwrap()),
+            expected1,
+            "seed {}:\n{}",
+            seed,
+            input
+        );
+        assert_eq!(
+            part2(&parse(&input).unwrap(), &Cancel::new()),
+            Ok(expected2),
+            "seed {}:\n{}",
+            seed,
+            input
+        );
+    }
+}
@@ -0,0 +1,16 @@
+//! Random puzzle inputs for property tests.
+
+use rand::{Rng, SeedableRng, rngs::StdRng};
+
+/// `size` junction boxes at random coordinates, reproducible from `seed`.
+pub fn input(seed: u64, size: usize) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let extent = rng.random_range(10..100_000);
+
+    (0..size)
+        .map(|_| {
+            let [x, y, z] = [(); 3].map(|_| rng.random_range(0..extent));
+            format!("{},{},{}\n", x, y, z)
+        })
+        .collect()
+}
@@ -0,0 +1,275 @@
+use std::collections::HashMap;
+
+use aoc_core::{
+    Line, ParseError, Solution, lines,
+    trace::{self, Event, Verbosity},
+};
+
+#[cfg(feature = "generate")]
+pub mod generate;
+
+#[derive(Debug, Clone)]
+pub struct Space {
+    pub positions: Vec<Position>,
+}
+
+impl Space {
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let positions = lines(input)
+            .map(Position::parse)
+            .collect::<Result<Vec<_>, _>>()?;
+        if positions.len() < 2 {
+            let last = lines(input).last();
+            return Err(match last {
+                Some(line) => ParseError::new(line.number + 1, 1, "", "a second junction box"),
+                None => ParseError::new(1, 1, "", "a junction box `x,y,z`"),
+            });
+        }
+        Ok(Space { positions })
+    }
+}
+
+/// Largest coordinate magnitude, small enough for squared distances to fit in an `i64`.
+pub const MAX_COORD: i64 = 1 << 29;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Position {
+    pub x: i64,
+    pub y: i64,
+    pub z: i64,
+}
+
+impl Position {
+    pub fn parse(line: Line) -> Result<Self, ParseError> {
+        let mut coords = line.text.trim().split(',').map(str::trim);
+        let mut next_coord = |axis: &str| match coords.next() {
+            Some(coord) => {
+                let value = line.parse::<i64>(coord, format!("an integer {} coordinate", axis))?;
+                if value.abs() > MAX_COORD {
+                    let expected = format!("a coordinate between -{} and {}", MAX_COORD, MAX_COORD);
+                    return Err(line.error(coord, expected));
+                }
+                Ok(value)
+            }
+            None => Err(line.missing(format!("a `,` and {} coordinate", axis))),
+        };
+
+        let position = Position {
+            x: next_coord("x")?,
+            y: next_coord("y")?,
+            z: next_coord("z")?,
+        };
+        if let Some(extra) = coords.next() {
+            return Err(line.error(extra, "end of line after three coordinates"));
+        }
+        Ok(position)
+    }
+
+    pub fn distance_squared(&self, other: &Position) -> i64 {
+        let dx = self.x - other.x;
+        let dy = self.y - other.y;
+        let dz = self.z - other.z;
+        dx * dx + dy * dy + dz * dz
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct UnionFind {
+    parent: Vec<usize>,
+    size: Vec<usize>,
+}
+
+impl UnionFind {
+    pub fn new(n: usize) -> Self {
+        UnionFind {
+            parent: (0..n).collect(),
+            size: vec![1; n],
+        }
+    }
+
+    pub fn find(&mut self, x: usize) -> usize {
+        if self.parent[x] != x {
+            self.parent[x] = self.find(self.parent[x]);
+        }
+        self.parent[x]
+    }
+
+    pub fn union(&mut self, x: usize, y: usize) -> bool {
+        let root_x = self.find(x);
+        let root_y = self.find(y);
+
+        if root_x == root_y {
+            return false;
+        }
+
+        if self.size[root_x] < self.size[root_y] {
+            self.parent[root_x] = root_y;
+            self.size[root_y] += self.size[root_x];
+        } else {
+            self.parent[root_y] = root_x;
+            self.size[root_x] += self.size[root_y];
+        }
+        true
+    }
+
+    pub fn get_component_sizes(&mut self) -> Vec<usize> {
+        let n = self.parent.len();
+        let mut sizes = HashMap::new();
+
+        for i in 0..n {
+            let root = self.find(i);
+            *sizes.entry(root).or_insert(0) += 1;
+        }
+
+        sizes.values().copied().collect()
+    }
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+
+    type Input = Space;
+    type Answer1 = usize;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Space::parse(input)
+    }
+
+    fn part1(space: &Self::Input) -> Self::Answer1 {
+        part1(space)
+    }
+
+    fn part2(space: &Self::Input) -> Self::Answer2 {
+        part2(space)
+    }
+}
+
+/// Joins the circuits of boxes `i` and `j`, tracing the union or the skipped pair.
+fn connect(uf: &mut UnionFind, dist: i64, i: usize, j: usize) {
+    if uf.union(i, j) {
+        trace::emit(Verbosity::Steps, || {
+            let root = uf.find(i);
+            Event::new("union")
+                .with("boxes", format!("{}-{}", i + 1, j + 1))
+                .with("distance_squared", dist)
+                .with("circuit", uf.size[root])
+        });
+    } else {
+        trace::emit(Verbosity::Details, || {
+            Event::new("skip").with("boxes", format!("{}-{}", i + 1, j + 1))
+        });
+    }
+}
+
+pub fn part1(space: &Space) -> usize {
+    let n = space.positions.len();
+
+    let mut edges: Vec<(i64, usize, usize)> = Vec::new();
+    for i in 0..n {
+        for j in (i + 1)..n {
+            let dist = space.positions[i].distance_squared(&space.positions[j]);
+            edges.push((dist, i, j));
+        }
+    }
+
+    edges.sort_by_key(|&(dist, _, _)| dist);
+
+    let num_pairs = if n == 20 { 10 } else { 1000 };
+    let mut uf = UnionFind::new(n);
+    for &(dist, i, j) in edges.iter().take(num_pairs) {
+        connect(&mut uf, dist, i, j);
+    }
+
+    let mut sizes = uf.get_component_sizes();
+    sizes.sort_by(|a, b| b.cmp(a));
+    trace::emit(Verbosity::Summary, || {
+        Event::new("circuits")
+            .with("count", sizes.len())
+            .with("largest", format!("{:?}", &sizes[..sizes.len().min(3)]))
+    });
+
+    // Fewer than three circuits are left if the pairs connect almost every box.
+    sizes.iter().take(3).product()
+}
+
+pub fn part2(space: &Space) -> i64 {
+    let n = space.positions.len();
+
+    let mut edges: Vec<(i64, usize, usize)> = Vec::new();
+    for i in 0..n {
+        for j in (i + 1)..n {
+            let dist = space.positions[i].distance_squared(&space.positions[j]);
+            edges.push((dist, i, j));
+        }
+    }
+
+    edges.sort_by_key(|&(dist, _, _)| dist);
+
+    let mut uf = UnionFind::new(n);
+    for (dist, i, j) in edges {
+        connect(&mut uf, dist, i, j);
+        if uf.size.contains(&n) {
+            return space.positions[i].x * space.positions[j].x;
+        }
+    }
+    unreachable!("connecting every pair joins all {} boxes", n)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../examples/example.txt");
+
+    aoc_core::example_tests!(Day08);
+
+    #[test]
+    fn test_explain() {
+        let space = Space::parse(EXAMPLE).unwrap();
+        let (_, events) = trace::explain(Verbosity::Details, || trace::capture(|| part1(&space)));
+        let unions = events.iter().filter(|e| e.kind == "union").count();
+        let skips = events.iter().filter(|e| e.kind == "skip").count();
+        assert_eq!((unions, skips), (9, 1));
+        assert_eq!(
+            events.last().unwrap().to_string(),
+            "circuits count=11 largest=[5, 4, 2]"
+        );
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            Space::parse("162,817,812\n57,618").err(),
+            Some(ParseError::new(2, 7, "", "a `,` and z coordinate"))
+        );
+        assert_eq!(
+            Space::parse("162,8a7,812").err(),
+            Some(ParseError::new(1, 5, "8a7", "an integer y coordinate"))
+        );
+        assert_eq!(
+            Space::parse("1,2,3\n4,5,999999999").err(),
+            Some(ParseError::new(
+                2,
+                5,
+                "999999999",
+                "a coordinate between -536870912 and 536870912"
+            ))
+        );
+        assert_eq!(
+            Space::parse("1,2,3").err(),
+            Some(ParseError::new(2, 1, "", "a second junction box"))
+        );
+        assert_eq!(
+            Space::parse("1,2,3,4").err(),
+            Some(ParseError::new(
+                1,
+                7,
+                "4",
+                "end of line after three coordinates"
+            ))
+        );
+    }
+}
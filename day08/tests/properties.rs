@@ -0,0 +1,82 @@
+use day08::{Position, Space, generate, part1, part2};
+
+/// Every pair of boxes, closest first.
+fn pairs(positions: &[Position]) -> Vec<(usize, usize)> {
+    let mut pairs = Vec::new();
+    for i in 0..positions.len() {
+        for j in i + 1..positions.len() {
+            pairs.push((i, j));
+        }
+    }
+    pairs.sort_by_key(|&(i, j)| positions[i].distance_squared(&positions[j]));
+    pairs
+}
+
+/// Sizes of the circuits formed by `connections`, found by flood fill.
+fn circuits(n: usize, connections: &[(usize, usize)]) -> Vec<usize> {
+    let mut adjacent = vec![Vec::new(); n];
+    for &(i, j) in connections {
+        adjacent[i].push(j);
+        adjacent[j].push(i);
+    }
+
+    let mut seen = vec![false; n];
+    let mut sizes = Vec::new();
+    for start in 0..n {
+        if seen[start] {
+            continue;
+        }
+        seen[start] = true;
+        let mut stack = vec![start];
+        let mut size = 0;
+        while let Some(i) = stack.pop() {
+            size += 1;
+            for &j in &adjacent[i] {
+                if !seen[j] {
+                    seen[j] = true;
+                    stack.push(j);
+                }
+            }
+        }
+        sizes.push(size);
+    }
+    sizes
+}
+
+#[test]
+fn test_part1_matches_flood_fill() {
+    // Twenty boxes is the size at which the puzzle connects ten pairs.
+    for seed in 0..500 {
+        let input = generate::input(seed, 20);
+        let space = Space::parse(&input).unwrap();
+
+        let pairs = pairs(&space.positions);
+        let mut sizes = circuits(20, &pairs[..10]);
+        sizes.sort_unstable_by(|a, b| b.cmp(a));
+
+        assert_eq!(
+            part1(&space),
+            sizes[0] * sizes[1] * sizes[2],
+            "seed {}",
+            seed
+        );
+    }
+}
+
+#[test]
+fn test_part2_matches_flood_fill() {
+    for seed in 0..500 {
+        let n = 2 + seed as usize % 30;
+        let input = generate::input(seed, n);
+        let space = Space::parse(&input).unwrap();
+
+        let pairs = pairs(&space.positions);
+        let connected = (1..=pairs.len())
+            .find(|&k| circuits(n, &pairs[..k]).len() == 1)
+            .unwrap();
+        let (i, j) = pairs[connected - 1];
+        let expected = space.positions[i].x * space.positions[j].x;
+
+        assert_eq!(part2(&space), expected, "seed {}:\n{}", seed, input);
+    }
+}
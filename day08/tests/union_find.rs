@@ -0,0 +1,17 @@
+use day08::UnionFind;
+
+#[test]
+fn test_union_find() {
+    let mut uf = UnionFind::new(5);
+
+    assert!(uf.union(0, 1));
+    assert!(uf.union(3, 4));
+    assert!(uf.union(1, 4));
+    assert!(!uf.union(0, 3));
+    assert_eq!(uf.find(0), uf.find(4));
+    assert_ne!(uf.find(0), uf.find(2));
+
+    let mut sizes = uf.get_component_sizes();
+    sizes.sort();
+    assert_eq!(sizes, vec![1, 4]);
+}
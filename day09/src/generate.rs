@@ -0,0 +1,56 @@
+//! Random puzzle inputs for property tests.
+
+use rand::{Rng, SeedableRng, rngs::StdRng, seq::index};
+
+/// The red tiles of a random loop of `size` vertical strips, reproducible from `seed`.
+///
+/// Each strip spans a range of rows that overlaps its neighbours', so the loop never
+/// touches itself: it walks along the strip tops from left to right, then back along
+/// the bottoms, turning at every red tile.
+pub fn input(seed: u64, size: usize) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let strips = size.max(1);
+    let height = rng.random_range(8..(4 * strips as i64).max(9));
+
+    let mut xs: Vec<i64> = index::sample(&mut rng, 3 * strips + 3, strips + 1)
+        .into_iter()
+        .map(|x| x as i64)
+        .collect();
+    xs.sort_unstable();
+
+    let mut tops: Vec<i64> = Vec::new();
+    let mut bottoms: Vec<i64> = Vec::new();
+    while tops.len() < strips {
+        // Keeping tops off the lowest rows and bottoms off the highest ones means a
+        // fitting strip always exists, whatever the previous one was.
+        let bottom = rng.random_range(0..height - 2);
+        let top = rng.random_range((bottom + 1).max(2)..height);
+        let fits = match (tops.last(), bottoms.last()) {
+            (Some(&prev_top), Some(&prev_bottom)) => {
+                top != prev_top && bottom != prev_bottom && bottom < prev_top && prev_bottom < top
+            }
+            _ => true,
+        };
+        if fits {
+            tops.push(top);
+            bottoms.push(bottom);
+        }
+    }
+
+    let mut tiles = vec![(xs[0], bottoms[0])];
+    for (i, &top) in tops.iter().enumerate() {
+        tiles.push((xs[i], top));
+        tiles.push((xs[i + 1], top));
+    }
+    for (i, &bottom) in bottoms.iter().enumerate().rev() {
+        tiles.push((xs[i + 1], bottom));
+        if i > 0 {
+            tiles.push((xs[i], bottom));
+        }
+    }
+
+    tiles
+        .iter()
+        .map(|(x, y)| format!("{},{}\n", x, y))
+        .collect()
+}
@@ -0,0 +1,252 @@
+use aoc_core::{Grid, Line, ParseError, Pos, Solution, lines};
+
+#[cfg(feature = "generate")]
+pub mod generate;
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+
+    type Input = Vec<(i64, i64)>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(coords: &Self::Input) -> Self::Answer1 {
+        part1(coords)
+    }
+
+    fn part2(coords: &Self::Input) -> Self::Answer2 {
+        part2(coords)
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<(i64, i64)>, ParseError> {
+    lines(input).map(parse_coord).collect()
+}
+
+/// Largest coordinate magnitude, small enough for rectangle areas to fit in an `i64`.
+pub const MAX_COORD: i64 = 1 << 30;
+
+fn parse_coord(line: Line) -> Result<(i64, i64), ParseError> {
+    let text = line.text.trim();
+    let (x, y) = text
+        .split_once(',')
+        .ok_or_else(|| line.error(text, "a coordinate pair `x,y`"))?;
+    Ok((
+        parse_axis(&line, x.trim(), "x")?,
+        parse_axis(&line, y.trim(), "y")?,
+    ))
+}
+
+fn parse_axis(line: &Line, token: &str, axis: &str) -> Result<i64, ParseError> {
+    let value = line.parse::<i64>(token, format!("an integer {} coordinate", axis))?;
+    if value.abs() > MAX_COORD {
+        let expected = format!("a coordinate between -{} and {}", MAX_COORD, MAX_COORD);
+        return Err(line.error(token, expected));
+    }
+    Ok(value)
+}
+
+pub fn part1(coords: &[(i64, i64)]) -> i64 {
+    let mut max_area = 0;
+    for i in 0..coords.len() {
+        for j in (i + 1)..coords.len() {
+            let area =
+                ((coords[i].0 - coords[j].0).abs() + 1) * ((coords[i].1 - coords[j].1).abs() + 1);
+            max_area = max_area.max(area);
+        }
+    }
+
+    max_area
+}
+
+/// Largest rectangle between two red tiles that only covers red or green tiles.
+///
+/// The tiles are compressed to the distinct coordinates of the red tiles and the gaps
+/// between them, so that every compressed cell is entirely inside or outside the loop.
+/// Flood-filling the outside from a padding border then tells which cells a rectangle
+/// may cover, and prefix sums check each candidate in constant time.
+pub fn part2(coords: &[(i64, i64)]) -> i64 {
+    let xs = Axis::new(coords.iter().map(|c| c.0));
+    let ys = Axis::new(coords.iter().map(|c| c.1));
+    let mut grid = Grid::new(xs.len(), ys.len(), Region::Unknown);
+
+    for (i, &(x1, y1)) in coords.iter().enumerate() {
+        let (x2, y2) = coords[(i + 1) % coords.len()];
+        let (cols, rows) = (xs.span(x1, x2), ys.span(y1, y2));
+        for row in rows {
+            for col in cols.clone() {
+                grid[Pos::new(row, col)] = Region::Loop;
+            }
+        }
+    }
+
+    let mut stack = vec![Pos::new(0, 0)];
+    grid[Pos::new(0, 0)] = Region::Outside;
+    while let Some(pos) = stack.pop() {
+        let unknown: Vec<Pos> = grid
+            .neighbours4(pos)
+            .filter(|&next| grid[next] == Region::Unknown)
+            .collect();
+        for next in unknown {
+            grid[next] = Region::Outside;
+            stack.push(next);
+        }
+    }
+
+    // outside[row][col]: outside tiles in the compressed cells above and left of (row, col).
+    let mut outside = vec![vec![0i64; xs.len() + 1]; ys.len() + 1];
+    for row in 0..ys.len() {
+        for col in 0..xs.len() {
+            let cell = if grid[Pos::new(row, col)] == Region::Outside {
+                xs.tiles(col) * ys.tiles(row)
+            } else {
+                0
+            };
+            outside[row + 1][col + 1] =
+                cell + outside[row][col + 1] + outside[row + 1][col] - outside[row][col];
+        }
+    }
+
+    let mut max_area = 0;
+    for i in 0..coords.len() {
+        for j in (i + 1)..coords.len() {
+            let area =
+                ((coords[i].0 - coords[j].0).abs() + 1) * ((coords[i].1 - coords[j].1).abs() + 1);
+            if area <= max_area {
+                continue;
+            }
+
+            let cols = xs.span(coords[i].0, coords[j].0);
+            let rows = ys.span(coords[i].1, coords[j].1);
+            let (top, left) = (*rows.start(), *cols.start());
+            let (bottom, right) = (*rows.end() + 1, *cols.end() + 1);
+            let covered = outside[bottom][right] - outside[top][right] - outside[bottom][left]
+                + outside[top][left];
+            if covered == 0 {
+                max_area = area;
+            }
+        }
+    }
+
+    max_area
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Region {
+    Unknown,
+    Loop,
+    Outside,
+}
+
+/// One axis of the compressed grid: index `2 * i + 1` is the `i`th distinct coordinate,
+/// the indices between are the gaps between coordinates, and the first and last index
+/// pad the grid so the outside is connected.
+struct Axis {
+    values: Vec<i64>,
+}
+
+impl Axis {
+    fn new(values: impl Iterator<Item = i64>) -> Self {
+        let mut values: Vec<i64> = values.collect();
+        values.sort_unstable();
+        values.dedup();
+        Axis { values }
+    }
+
+    fn len(&self) -> usize {
+        2 * self.values.len() + 1
+    }
+
+    fn index(&self, value: i64) -> usize {
+        2 * self.values.binary_search(&value).unwrap() + 1
+    }
+
+    /// The compressed indices from one coordinate to another, inclusive.
+    fn span(&self, a: i64, b: i64) -> std::ops::RangeInclusive<usize> {
+        let (a, b) = (self.index(a), self.index(b));
+        a.min(b)..=a.max(b)
+    }
+
+    /// Number of tiles along this axis in compressed index `i`.
+    fn tiles(&self, i: usize) -> i64 {
+        match i {
+            _ if i % 2 == 1 => 1,
+            0 => 0,
+            _ if i == self.len() - 1 => 0,
+            _ => self.values[i / 2] - self.values[i / 2 - 1] - 1,
+        }
+    }
+}
+
+/// Whether `point` lies inside `polygon` or on its boundary. `part2` no longer uses it,
+/// since it checks whole rectangles on a compressed grid.
+pub fn is_point_inside_polygon(point: (i64, i64), polygon: &[(i64, i64)]) -> bool {
+    let (x, y) = point;
+    let n = polygon.len();
+
+    for i in 0..n {
+        let j = (i + 1) % n;
+        let (x1, y1) = polygon[i];
+        let (x2, y2) = polygon[j];
+
+        let min_x = x1.min(x2);
+        let max_x = x1.max(x2);
+        let min_y = y1.min(y2);
+        let max_y = y1.max(y2);
+
+        if x >= min_x && x <= max_x && y >= min_y && y <= max_y {
+            let cross = (x2 - x1) * (y - y1) - (y2 - y1) * (x - x1);
+            if cross == 0 {
+                return true;
+            }
+        }
+    }
+
+    let mut inside = false;
+
+    for i in 0..n {
+        let j = (i + 1) % n;
+        let (xi, yi) = polygon[i];
+        let (xj, yj) = polygon[j];
+
+        if ((yi > y) != (yj > y)) && (x < (xj - xi) * (y - yi) / (yj - yi) + xi) {
+            inside = !inside;
+        }
+    }
+
+    inside
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_core::example_tests!(Day09);
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            parse("7,1\n11;1").unwrap_err(),
+            ParseError::new(2, 1, "11;1", "a coordinate pair `x,y`")
+        );
+        assert_eq!(
+            parse("7,1\n11,-").unwrap_err(),
+            ParseError::new(2, 4, "-", "an integer y coordinate")
+        );
+        assert_eq!(
+            parse("7,1\n-2000000000,1").unwrap_err(),
+            ParseError::new(
+                2,
+                1,
+                "-2000000000",
+                "a coordinate between -1073741824 and 1073741824"
+            )
+        );
+    }
+}
@@ -0,0 +1,34 @@
+use day09::{is_point_inside_polygon, parse, part2};
+
+const POLYGON: [(i64, i64); 6] = [(0, 0), (10, 0), (10, 10), (5, 10), (5, 5), (0, 5)];
+
+#[test]
+fn test_is_point_inside_polygon() {
+    assert!(is_point_inside_polygon((2, 2), &POLYGON));
+    assert!(is_point_inside_polygon((7, 8), &POLYGON));
+    assert!(!is_point_inside_polygon((2, 8), &POLYGON));
+    assert!(!is_point_inside_polygon((11, 3), &POLYGON));
+}
+
+#[test]
+fn test_is_point_on_boundary() {
+    assert!(is_point_inside_polygon((0, 0), &POLYGON));
+    assert!(is_point_inside_polygon((5, 7), &POLYGON));
+    assert!(is_point_inside_polygon((3, 5), &POLYGON));
+}
+
+#[test]
+fn test_part2_l_shape() {
+    // An L: the 11 by 6 bottom bar and the 6 by 11 right column tie for the largest fit.
+    let coords = parse("0,0\n10,0\n10,10\n5,10\n5,5\n0,5").unwrap();
+    assert_eq!(part2(&coords), 66);
+}
+
+#[test]
+fn test_part2_rejects_rectangle_across_a_notch() {
+    // A one-tile slot runs up from the bottom edge between x = 10 and x = 12. The
+    // rectangle from (10,100) to (100,0) has every corner and sampled edge point inside,
+    // but spans the slot.
+    let coords = parse("0,0\n10,0\n10,100\n12,100\n12,0\n100,0\n100,101\n0,101").unwrap();
+    assert_eq!(part2(&coords), 89 * 102);
+}
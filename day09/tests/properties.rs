@@ -0,0 +1,61 @@
+use day09::{generate, parse, part1, part2};
+
+/// Whether `(x, y)` lies on the loop or inside it, by counting the vertical edges crossed
+/// on the way from `x` to the right.
+fn is_red_or_green(tiles: &[(i64, i64)], (x, y): (i64, i64)) -> bool {
+    let mut inside = false;
+    for (i, &(x1, y1)) in tiles.iter().enumerate() {
+        let (x2, y2) = tiles[(i + 1) % tiles.len()];
+        let on_edge =
+            (x1.min(x2)..=x1.max(x2)).contains(&x) && (y1.min(y2)..=y1.max(y2)).contains(&y);
+        if on_edge {
+            return true;
+        }
+        if x1 == x2 && x1 > x && (y1.min(y2)..y1.max(y2)).contains(&y) {
+            inside = !inside;
+        }
+    }
+    inside
+}
+
+/// Largest rectangles between two red tiles, checking every tile of each rectangle.
+fn reference(tiles: &[(i64, i64)]) -> (i64, i64) {
+    let width = tiles.iter().map(|t| t.0).max().unwrap() + 1;
+    let height = tiles.iter().map(|t| t.1).max().unwrap() + 1;
+    let allowed: Vec<Vec<bool>> = (0..width)
+        .map(|x| {
+            (0..height)
+                .map(|y| is_red_or_green(tiles, (x, y)))
+                .collect()
+        })
+        .collect();
+
+    let (mut largest, mut largest_allowed) = (0, 0);
+    for (i, &(x1, y1)) in tiles.iter().enumerate() {
+        for &(x2, y2) in &tiles[i + 1..] {
+            let (xs, ys) = (x1.min(x2)..=x1.max(x2), y1.min(y2)..=y1.max(y2));
+            let area = ((x1 - x2).abs() + 1) * ((y1 - y2).abs() + 1);
+            largest = largest.max(area);
+
+            let all_allowed = xs
+                .clone()
+                .all(|x| ys.clone().all(|y| allowed[x as usize][y as usize]));
+            if all_allowed {
+                largest_allowed = largest_allowed.max(area);
+            }
+        }
+    }
+    (largest, largest_allowed)
+}
+
+#[test]
+fn test_parts_match_tile_by_tile_check() {
+    for seed in 0..1000 {
+        let input = generate::input(seed, 1 + seed as usize % 6);
+        let tiles = parse(&input).unwrap();
+        let (expected1, expected2) = reference(&tiles);
+
+        assert_eq!(part1(&tiles), expected1, "seed {}:\n{}", seed, input);
+        assert_eq!(part2(&tiles), expected2, "seed {}:\n{}", seed, input);
+    }
+}
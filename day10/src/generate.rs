@@ -0,0 +1,74 @@
+//! Random puzzle inputs for property tests.
+
+use rand::{Rng, SeedableRng, rngs::StdRng, seq::index};
+
+/// `size` random machines, reproducible from `seed`. Both the light pattern and the
+/// joltages come from pressing the machine's own buttons, so every machine is solvable;
+/// like real inputs, no pattern has all lights off and no joltages are all zero.
+pub fn input(seed: u64, size: usize) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    (0..size).map(|_| machine(&mut rng) + "\n").collect()
+}
+
+fn machine(rng: &mut StdRng) -> String {
+    let lights = rng.random_range(2..6);
+    let buttons: Vec<Vec<usize>> = (0..rng.random_range(1..6))
+        .map(|_| {
+            let count = rng.random_range(1..=lights);
+            let mut positions = index::sample(rng, lights, count).into_vec();
+            positions.sort_unstable();
+            positions
+        })
+        .collect();
+
+    let pattern = loop {
+        let mut on = vec![false; lights];
+        for button in &buttons {
+            if rng.random_bool(0.5) {
+                for &pos in button {
+                    on[pos] = !on[pos];
+                }
+            }
+        }
+        if on.contains(&true) {
+            break on;
+        }
+    };
+
+    let joltages = loop {
+        let mut joltages = vec![0; lights];
+        for button in &buttons {
+            for _ in 0..rng.random_range(0..4) {
+                for &pos in button {
+                    joltages[pos] += 1;
+                }
+            }
+        }
+        if joltages.iter().any(|&j| j > 0) {
+            break joltages;
+        }
+    };
+
+    let pattern: String = pattern
+        .iter()
+        .map(|&on| if on { '#' } else { '.' })
+        .collect();
+    let buttons: Vec<String> = buttons
+        .iter()
+        .map(|button| format!("({})", list(button)))
+        .collect();
+    format!(
+        "[{}] {} {{{}}}",
+        pattern,
+        buttons.join(" "),
+        list(&joltages)
+    )
+}
+
+fn list<T: ToString>(values: &[T]) -> String {
+    values
+        .iter()
+        .map(T::to_string)
+        .collect::<Vec<_>>()
+        .join(",")
+}
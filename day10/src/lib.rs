@@ -0,0 +1,375 @@
+use std::{collections::HashMap, str::FromStr};
+
+use aoc_core::{
+    Cancel, Line, ParseError, Part, Solution, Strategy, TimedOut, lines,
+    trace::{self, Event, Verbosity},
+};
+use rand::{Rng, rng};
+
+#[cfg(feature = "generate")]
+pub mod generate;
+
+/// Most lights a machine may have, so that a set of them fits a `u64` mask.
+pub const MAX_LIGHTS: usize = u64::BITS as usize;
+
+/// Most buttons a machine may have, so that a subset of them fits a `u64` mask.
+pub const MAX_BUTTONS: usize = u64::BITS as usize - 1;
+
+#[derive(Debug, Clone)]
+pub struct Machine {
+    pub pattern: String,
+    pub buttons: Vec<Button>,
+    pub joltages: Vec<u64>,
+}
+
+impl Machine {
+    pub fn parse(line: Line) -> Result<Self, ParseError> {
+        let mut pattern = None;
+        let mut buttons = Vec::new();
+        let mut joltages = None;
+
+        for token in line.text.split_whitespace() {
+            if let Some(lights) = strip_delimiters(token, '[', ']') {
+                if let Some((i, c)) = lights.char_indices().find(|&(_, c)| c != '.' && c != '#') {
+                    return Err(line.error(&lights[i..i + c.len_utf8()], "a light `.` or `#`"));
+                }
+                if lights.len() > MAX_LIGHTS {
+                    return Err(line.error(
+                        lights,
+                        format!("a pattern of at most {} lights", MAX_LIGHTS),
+                    ));
+                }
+                pattern = Some(lights);
+            } else if let Some(list) = strip_delimiters(token, '(', ')') {
+                if buttons.len() == MAX_BUTTONS {
+                    return Err(line.error(token, format!("at most {} buttons", MAX_BUTTONS)));
+                }
+                let positions = parse_list(line, list, "a light index")?;
+                buttons.push(Button { positions });
+            } else if let Some(list) = strip_delimiters(token, '{', '}') {
+                joltages = Some(parse_list(line, list, "a joltage")?);
+            } else {
+                return Err(line.error(token, "a `[pattern]`, `(button)` or `{joltages}`"));
+            }
+        }
+
+        let pattern = pattern.ok_or_else(|| line.missing("a `[pattern]`"))?;
+        let joltages = joltages.ok_or_else(|| line.missing("`{joltages}`"))?;
+
+        Ok(Machine {
+            pattern: pattern.to_string(),
+            buttons,
+            joltages,
+        })
+    }
+}
+
+fn strip_delimiters(token: &str, open: char, close: char) -> Option<&str> {
+    token.strip_prefix(open)?.strip_suffix(close)
+}
+
+fn parse_list<T: FromStr>(line: Line, list: &str, expected: &str) -> Result<Vec<T>, ParseError> {
+    list.split(',')
+        .map(|s| line.parse(s.trim(), expected))
+        .collect()
+}
+
+#[derive(Debug, Clone)]
+pub struct Button {
+    pub positions: Vec<usize>,
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    type Input = Vec<Machine>;
+    type Answer1 = Result<usize, TimedOut>;
+    type Answer2 = Result<usize, TimedOut>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(machines: &Self::Input) -> Self::Answer1 {
+        part1(machines, &Cancel::current())
+    }
+
+    fn part2(machines: &Self::Input) -> Self::Answer2 {
+        part2(machines, &Cancel::current())
+    }
+
+    fn strategies() -> Vec<Strategy<Self::Input>> {
+        vec![
+            Strategy::new(Part::One, "sampling", |machines| {
+                part1(machines, &Cancel::current()).into()
+            }),
+            Strategy::new(Part::One, "exhaustive", |machines| {
+                part1_exhaustive(machines).into()
+            }),
+        ]
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<Machine>, ParseError> {
+    lines(input).map(Machine::parse).collect()
+}
+
+pub fn part1(machines: &[Machine], cancel: &Cancel) -> Result<usize, TimedOut> {
+    const SAMPLES: usize = 10000;
+    const MAX_STEPS: usize = 10;
+
+    let mut steps_per_machine = Vec::new();
+
+    for (line, machine) in machines.iter().enumerate() {
+        let desired_state = machine
+            .pattern
+            .chars()
+            .map(|c| c == '#')
+            .collect::<Vec<_>>();
+        let mut steps = MAX_STEPS + 1;
+
+        for _ in 0..SAMPLES {
+            cancel.check()?;
+            let mut current_state = vec![false; machine.pattern.len()];
+            for i in 0..MAX_STEPS {
+                let button_idx = rng().random_range(0..machine.buttons.len());
+                let button = &machine.buttons[button_idx];
+                for &pos in &button.positions {
+                    if pos < current_state.len() {
+                        current_state[pos] = !current_state[pos];
+                    }
+                }
+                if current_state == desired_state {
+                    steps = steps.min(i + 1);
+                    break;
+                }
+            }
+        }
+
+        if steps == MAX_STEPS + 1 {
+            panic!(
+                "Machine {:?} could not complete any sample in {} steps",
+                machine, MAX_STEPS
+            );
+        }
+        trace::emit(Verbosity::Steps, || {
+            Event::new("machine")
+                .with("line", line + 1)
+                .with("presses", steps)
+        });
+        steps_per_machine.push(steps);
+    }
+
+    Ok(steps_per_machine.iter().sum())
+}
+
+/// Exact version of `part1`: pressing a button twice cancels out, so the fewest presses is
+/// the smallest subset of buttons whose toggles combine into the pattern.
+pub fn part1_exhaustive(machines: &[Machine]) -> usize {
+    machines
+        .iter()
+        .map(|machine| {
+            let lights = machine.pattern.len();
+            let target = light_mask(machine.pattern.match_indices('#').map(|(i, _)| i), lights);
+            let buttons: Vec<u64> = machine
+                .buttons
+                .iter()
+                .map(|button| light_mask(button.positions.iter().copied(), lights))
+                .collect();
+
+            (0u64..1 << buttons.len())
+                .filter(|subset| {
+                    let toggled = buttons
+                        .iter()
+                        .enumerate()
+                        .filter(|&(i, _)| subset & (1 << i) != 0)
+                        .fold(0, |mask, (_, &button)| mask ^ button);
+                    toggled == target
+                })
+                .map(|subset| subset.count_ones() as usize)
+                .min()
+                .unwrap_or_else(|| panic!("Machine {:?} cannot reach its pattern", machine))
+        })
+        .sum()
+}
+
+fn light_mask(positions: impl Iterator<Item = usize>, lights: usize) -> u64 {
+    positions
+        .filter(|&pos| pos < lights)
+        .fold(0, |mask, pos| mask ^ (1 << pos))
+}
+
+pub fn part2(machines: &[Machine], cancel: &Cancel) -> Result<usize, TimedOut> {
+    let mut steps_per_machine = Vec::new();
+
+    for (line, machine) in machines.iter().enumerate() {
+        let steps =
+            joltage_cost(&machine.buttons, &machine.joltages, cancel)?.expect("No solution found");
+        trace::emit(Verbosity::Steps, || {
+            Event::new("machine")
+                .with("line", line + 1)
+                .with("presses", steps)
+        });
+        steps_per_machine.push(steps);
+    }
+
+    Ok(steps_per_machine.iter().sum())
+}
+
+/// Fewest presses that raise the counters to `joltage`, or `None` if no presses can.
+pub fn joltage_cost(
+    buttons: &[Button],
+    joltage: &[u64],
+    cancel: &Cancel,
+) -> Result<Option<usize>, TimedOut> {
+    let n = joltage.len();
+
+    let mut press_patterns: HashMap<Vec<u64>, Vec<Vec<usize>>> = HashMap::new();
+
+    let n_buttons = buttons.len();
+    for mask in 0u64..1 << n_buttons {
+        if mask % 1024 == 0 {
+            cancel.check()?;
+        }
+        let mut combo = Vec::new();
+        for i in 0..n_buttons {
+            if mask & (1 << i) != 0 {
+                combo.push(i);
+            }
+        }
+
+        let pattern = pattern(&press(&combo, buttons, n));
+        press_patterns.entry(pattern).or_default().push(combo);
+    }
+
+    let mut cache: HashMap<Vec<u64>, usize> = HashMap::new();
+
+    fn cost_recursive(
+        jolts: &[u64],
+        press_patterns: &HashMap<Vec<u64>, Vec<Vec<usize>>>,
+        buttons: &[Button],
+        target_sum: usize,
+        cache: &mut HashMap<Vec<u64>, usize>,
+        cancel: &Cancel,
+    ) -> Result<usize, TimedOut> {
+        if jolts.iter().all(|&j| j == 0) {
+            return Ok(0);
+        }
+
+        let jolts_vec = jolts.to_vec();
+        if let Some(&cached) = cache.get(&jolts_vec) {
+            return Ok(cached);
+        }
+        cancel.check()?;
+
+        let pat = pattern(jolts);
+
+        if !press_patterns.contains_key(&pat) {
+            return Ok(target_sum);
+        }
+
+        let mut min_cost = target_sum;
+
+        for btn_combo in &press_patterns[&pat] {
+            let pressed = press(btn_combo, buttons, jolts.len());
+
+            let can_subtract = jolts.iter().zip(pressed.iter()).all(|(&a, &b)| a >= b);
+            if !can_subtract {
+                continue;
+            }
+
+            let halved = sub_halve(jolts, &pressed);
+
+            let sub_cost =
+                cost_recursive(&halved, press_patterns, buttons, target_sum, cache, cancel)?;
+            let total = btn_combo.len() + 2 * sub_cost;
+            min_cost = min_cost.min(total);
+        }
+
+        cache.insert(jolts_vec, min_cost);
+        Ok(min_cost)
+    }
+
+    // Every press adds at least one joltage, so no solution costs more than the joltage sum.
+    let target_sum: usize = joltage.iter().map(|&x| x as usize).sum::<usize>() + 1;
+    let result = cost_recursive(
+        joltage,
+        &press_patterns,
+        buttons,
+        target_sum,
+        &mut cache,
+        cancel,
+    )?;
+
+    Ok((result < target_sum).then_some(result))
+}
+
+fn press(btn_indices: &[usize], buttons: &[Button], n_positions: usize) -> Vec<u64> {
+    let mut result = vec![0u64; n_positions];
+    for &btn_idx in btn_indices {
+        for &pos in &buttons[btn_idx].positions {
+            if pos < n_positions {
+                result[pos] += 1;
+            }
+        }
+    }
+    result
+}
+
+fn pattern(jolts: &[u64]) -> Vec<u64> {
+    jolts.iter().map(|&j| j % 2).collect()
+}
+
+fn sub_halve(j_a: &[u64], j_b: &[u64]) -> Vec<u64> {
+    j_a.iter()
+        .zip(j_b.iter())
+        .map(|(&a, &b)| (a - b) / 2)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_core::example_tests!(Day10);
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            parse("[.##.] (3) (1,x) {3,5}").unwrap_err(),
+            ParseError::new(1, 15, "x", "a light index")
+        );
+        assert_eq!(
+            parse("[.#*.] (3) {3,5}").unwrap_err(),
+            ParseError::new(1, 4, "*", "a light `.` or `#`")
+        );
+        assert_eq!(
+            parse("[.##.] (3) <1> {3}").unwrap_err(),
+            ParseError::new(1, 12, "<1>", "a `[pattern]`, `(button)` or `{joltages}`")
+        );
+        assert_eq!(
+            parse("[.##.] (3) (1,3)").unwrap_err(),
+            ParseError::new(1, 17, "", "`{joltages}`")
+        );
+
+        let lights = format!("[{}] (0) {{1}}", ".".repeat(MAX_LIGHTS + 1));
+        assert_eq!(
+            parse(&lights).unwrap_err(),
+            ParseError::new(
+                1,
+                2,
+                &lights[1..MAX_LIGHTS + 2],
+                "a pattern of at most 64 lights"
+            )
+        );
+        assert!(parse(&format!("[{}] (0) {{1}}", ".".repeat(MAX_LIGHTS))).is_ok());
+        let buttons = format!("[.] {}{{1}}", "(0) ".repeat(MAX_BUTTONS + 1));
+        assert_eq!(
+            parse(&buttons).unwrap_err(),
+            ParseError::new(1, 4 + 4 * MAX_BUTTONS + 1, "(0)", "at most 63 buttons")
+        );
+        assert!(parse(&format!("[.] {}{{1}}", "(0) ".repeat(MAX_BUTTONS))).is_ok());
+    }
+}
@@ -0,0 +1,39 @@
+use aoc_core::{Cancel, TimedOut};
+use day10::{Button, joltage_cost};
+
+#[test]
+fn test_joltage_cost() {
+    let buttons = vec![
+        Button {
+            positions: vec![0, 1],
+        },
+        Button { positions: vec![1] },
+    ];
+
+    assert_eq!(joltage_cost(&buttons, &[2, 5], &Cancel::new()), Ok(Some(5)));
+    assert_eq!(joltage_cost(&buttons, &[3, 1], &Cancel::new()), Ok(None));
+}
+
+#[test]
+fn test_joltage_cost_cancelled() {
+    let buttons: Vec<Button> = (0..12)
+        .map(|i| Button {
+            positions: vec![i % 4, (i + 1) % 4],
+        })
+        .collect();
+    let cancel = Cancel::new();
+    cancel.cancel();
+
+    assert_eq!(
+        joltage_cost(&buttons, &[40, 60, 80, 100], &cancel),
+        Err(TimedOut)
+    );
+}
+
+#[test]
+fn test_joltage_cost_of_the_joltage_sum() {
+    // Single-position buttons cost exactly the joltage sum, which must still count.
+    let buttons = vec![Button { positions: vec![0] }, Button { positions: vec![1] }];
+
+    assert_eq!(joltage_cost(&buttons, &[2, 3], &Cancel::new()), Ok(Some(5)));
+}
@@ -0,0 +1,100 @@
+use std::collections::{HashSet, VecDeque};
+
+use aoc_core::Cancel;
+use day10::{Machine, generate, parse, part1, part1_exhaustive, part2};
+
+/// Fewest presses to light the pattern, by breadth-first search over light states.
+fn fewest_toggles(machine: &Machine) -> usize {
+    let target: Vec<bool> = machine.pattern.chars().map(|c| c == '#').collect();
+    let start = vec![false; target.len()];
+    let mut seen = HashSet::from([start.clone()]);
+    let mut queue = VecDeque::from([(start, 0)]);
+
+    while let Some((state, presses)) = queue.pop_front() {
+        if state == target {
+            return presses;
+        }
+        for button in &machine.buttons {
+            let mut next = state.clone();
+            for &pos in &button.positions {
+                next[pos] = !next[pos];
+            }
+            if seen.insert(next.clone()) {
+                queue.push_back((next, presses + 1));
+            }
+        }
+    }
+    panic!("unreachable pattern in {:?}", machine);
+}
+
+/// Fewest presses to reach the joltages, trying every number of presses of each button.
+fn fewest_increments(machine: &Machine) -> usize {
+    fn search(machine: &Machine, button: usize, remaining: &mut [u64]) -> Option<usize> {
+        let Some(positions) = machine.buttons.get(button).map(|b| &b.positions) else {
+            return remaining.iter().all(|&j| j == 0).then_some(0);
+        };
+
+        let most = positions.iter().map(|&pos| remaining[pos]).min().unwrap();
+        let mut best = None;
+        for presses in 0..=most {
+            for &pos in positions {
+                remaining[pos] -= presses;
+            }
+            if let Some(rest) = search(machine, button + 1, remaining) {
+                best = best
+                    .min(Some(presses as usize + rest))
+                    .or(Some(presses as usize + rest));
+            }
+            for &pos in positions {
+                remaining[pos] += presses;
+            }
+        }
+        best
+    }
+
+    search(machine, 0, &mut machine.joltages.clone()).unwrap()
+}
+
+#[test]
+fn test_part1_matches_breadth_first_search() {
+    for seed in 0..1000 {
+        let input = generate::input(seed, 1 + seed as usize % 3);
+        let machines = parse(&input).unwrap();
+        let expected: usize = machines.iter().map(fewest_toggles).sum();
+
+        assert_eq!(
+            part1_exhaustive(&machines),
+            expected,
+            "seed {}:\n{}",
+            seed,
+            input
+        );
+        // Sampling is slow, so it only gets some of the seeds.
+        if seed % 50 == 0 {
+            assert_eq!(
+                part1(&machines, &Cancel::new()),
+                Ok(expected),
+                "seed {}:\n{}",
+                seed,
+                input
+            );
+        }
+    }
+}
+
+#[test]
+fn test_part2_matches_exhaustive_presses() {
+    for seed in 0..1000 {
+        let input = generate::input(seed, 1 + seed as usize % 3);
+        let machines = parse(&input).unwrap();
+        let expected: usize = machines.iter().map(fewest_increments).sum();
+
+        assert_eq!(
+            part2(&machines, &Cancel::new()),
+            Ok(expected),
+            "seed {}:\n{}",
+            seed,
+            input
+        );
+    }
+}
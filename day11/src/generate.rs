@@ -0,0 +1,34 @@
+//! Random puzzle inputs for property tests.
+
+use rand::{Rng, SeedableRng, rngs::StdRng, seq::SliceRandom};
+
+/// A random rack of `you`, `svr`, `dac`, `fft` and `size` other devices, reproducible
+/// from `seed`. Devices only connect to devices later in a random order, or to `out`,
+/// so that there are no cycles and every device has at least one output.
+pub fn input(seed: u64, size: usize) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut labels: Vec<String> = ["you", "svr", "dac", "fft"]
+        .into_iter()
+        .map(String::from)
+        .chain((0..size).map(|i| format!("d{:02}", i)))
+        .collect();
+    labels.shuffle(&mut rng);
+
+    let density = rng.random_range(0.2..0.6);
+    let mut lines = Vec::new();
+    for (i, label) in labels.iter().enumerate() {
+        let mut outputs: Vec<&str> = labels[i + 1..]
+            .iter()
+            .filter(|_| rng.random_bool(density))
+            .map(String::as_str)
+            .collect();
+        if outputs.is_empty() || rng.random_bool(density / 2.0) {
+            outputs.push("out");
+        }
+        outputs.shuffle(&mut rng);
+        lines.push(format!("{}: {}\n", label, outputs.join(" ")));
+    }
+
+    lines.shuffle(&mut rng);
+    lines.concat()
+}
@@ -0,0 +1,179 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use aoc_core::{Cancel, Line, ParseError, Solution, TimedOut, lines};
+
+#[cfg(feature = "generate")]
+pub mod generate;
+
+#[derive(Debug, Clone)]
+pub struct Device {
+    pub label: String,
+    pub outputs: Vec<String>,
+}
+
+impl Device {
+    pub fn parse(line: Line) -> Result<Self, ParseError> {
+        let (label, outputs) = line
+            .text
+            .split_once(": ")
+            .ok_or_else(|| line.error(line.text, "a device `label: outputs`"))?;
+        if label.is_empty() || label.contains(char::is_whitespace) {
+            return Err(line.error(label, "a device label"));
+        }
+
+        Ok(Device {
+            label: label.to_string(),
+            outputs: outputs.split_whitespace().map(|s| s.to_string()).collect(),
+        })
+    }
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+
+    type Input = Vec<Device>;
+    type Answer1 = usize;
+    type Answer2 = Result<usize, TimedOut>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(devices: &Self::Input) -> Self::Answer1 {
+        part1(devices)
+    }
+
+    fn part2(devices: &Self::Input) -> Self::Answer2 {
+        part2(devices, &Cancel::current())
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<Device>, ParseError> {
+    lines(input).map(Device::parse).collect()
+}
+
+pub fn part1(devices: &[Device]) -> usize {
+    let mut queue = VecDeque::new();
+
+    // Part 2 inputs need not have a `you` device, and then there is no path from it.
+    let Some(starting_node) = devices.iter().find(|d| d.label == "you") else {
+        return 0;
+    };
+    queue.push_back(starting_node);
+
+    let mut total = 0;
+    while !queue.is_empty() {
+        let current = queue.pop_front().unwrap();
+
+        for label in &current.outputs {
+            if label == "out" {
+                total += 1;
+                continue;
+            }
+            if let Some(next_device) = devices.iter().find(|d| &d.label == label) {
+                queue.push_back(next_device);
+            }
+        }
+    }
+
+    total
+}
+
+pub fn part2(devices: &[Device], cancel: &Cancel) -> Result<usize, TimedOut> {
+    let mut graph: HashMap<&str, Vec<&str>> = HashMap::new();
+    for device in devices {
+        graph.insert(
+            &device.label,
+            device.outputs.iter().map(|s| s.as_str()).collect(),
+        );
+    }
+
+    type State<'a> = (&'a str, bool, bool);
+    let mut dp: HashMap<State, Option<usize>> = HashMap::new();
+
+    fn count_paths<'a>(
+        node: &'a str,
+        has_dac: bool,
+        has_fft: bool,
+        graph: &HashMap<&'a str, Vec<&'a str>>,
+        dp: &mut HashMap<State<'a>, Option<usize>>,
+        in_stack: &mut HashSet<State<'a>>,
+        cancel: &Cancel,
+    ) -> Result<usize, TimedOut> {
+        let new_has_dac = has_dac || node == "dac";
+        let new_has_fft = has_fft || node == "fft";
+        let state = (node, new_has_dac, new_has_fft);
+
+        if in_stack.contains(&state) {
+            return Ok(0);
+        }
+
+        if let Some(&Some(result)) = dp.get(&state) {
+            return Ok(result);
+        }
+        cancel.check()?;
+
+        in_stack.insert(state);
+
+        let outputs = match graph.get(node) {
+            Some(o) => o,
+            None => {
+                in_stack.remove(&state);
+                return Ok(0);
+            }
+        };
+
+        let mut total = 0;
+        for &next in outputs {
+            if next == "out" {
+                if new_has_dac && new_has_fft {
+                    total += 1;
+                }
+            } else {
+                total += count_paths(next, new_has_dac, new_has_fft, graph, dp, in_stack, cancel)?;
+            }
+        }
+
+        in_stack.remove(&state);
+        dp.insert(state, Some(total));
+        Ok(total)
+    }
+
+    let mut in_stack = HashSet::new();
+    count_paths("svr", false, false, &graph, &mut dp, &mut in_stack, cancel)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_core::example_tests!(Day11);
+
+    #[test]
+    fn test_part1_without_you() {
+        let devices = parse(include_str!("../examples/example.txt")).unwrap();
+        assert_eq!(part1(&devices[..1]), 0);
+    }
+
+    #[test]
+    fn test_part2_cancelled() {
+        let devices = parse(include_str!("../examples/example-2.txt")).unwrap();
+        let cancel = Cancel::new();
+        cancel.cancel();
+        assert_eq!(part2(&devices, &cancel), Err(TimedOut));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            parse("aaa: you hhh\nyou bbb ccc").unwrap_err(),
+            ParseError::new(2, 1, "you bbb ccc", "a device `label: outputs`")
+        );
+        assert_eq!(
+            parse(": out").unwrap_err(),
+            ParseError::new(1, 1, "", "a device label")
+        );
+    }
+}
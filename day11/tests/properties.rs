@@ -0,0 +1,58 @@
+use std::collections::HashMap;
+
+use aoc_core::Cancel;
+use day11::{Device, generate, parse, part1, part2};
+
+/// Every path from `from` to `out`, as the devices visited along the way.
+fn paths<'a>(graph: &HashMap<&'a str, Vec<&'a str>>, from: &'a str) -> Vec<Vec<&'a str>> {
+    let mut paths = Vec::new();
+    let mut stack = vec![vec![from]];
+    while let Some(path) = stack.pop() {
+        for &next in &graph[path.last().unwrap()] {
+            if next == "out" {
+                paths.push(path.clone());
+            } else {
+                let mut longer = path.clone();
+                longer.push(next);
+                stack.push(longer);
+            }
+        }
+    }
+    paths
+}
+
+fn reference(devices: &[Device]) -> (usize, usize) {
+    let graph: HashMap<&str, Vec<&str>> = devices
+        .iter()
+        .map(|d| {
+            (
+                d.label.as_str(),
+                d.outputs.iter().map(String::as_str).collect(),
+            )
+        })
+        .collect();
+
+    let through_both = paths(&graph, "svr")
+        .iter()
+        .filter(|path| path.contains(&"dac") && path.contains(&"fft"))
+        .count();
+    (paths(&graph, "you").len(), through_both)
+}
+
+#[test]
+fn test_parts_match_path_enumeration() {
+    for seed in 0..1000 {
+        let input = generate::input(seed, seed as usize % 9);
+        let devices = parse(&input).unwrap();
+        let (expected1, expected2) = reference(&devices);
+
+        assert_eq!(part1(&devices), expected1, "seed {}:\n{}", seed, input);
+        assert_eq!(
+            part2(&devices, &Cancel::new()),
+            Ok(expected2),
+            "seed {}:\n{}",
+            seed,
+            input
+        );
+    }
+}
@@ -0,0 +1,54 @@
+//! Random puzzle inputs for property tests.
+
+use rand::{Rng, SeedableRng, rngs::StdRng};
+
+const SHAPES: usize = 6;
+
+/// Six random 3x3 shapes and `size` regions, reproducible from `seed`.
+///
+/// Like real inputs, every region is easy to decide: either its presents fit side by side
+/// in 3x3 tiles, or they have more `#` cells than the region has room for.
+pub fn input(seed: u64, size: usize) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut out = String::new();
+
+    let mut cells = Vec::new();
+    for index in 0..SHAPES {
+        let mut shape = [false; 9];
+        shape[rng.random_range(0..9)] = true;
+        for cell in &mut shape {
+            *cell |= rng.random_bool(0.7);
+        }
+        cells.push(shape.iter().filter(|&&c| c).count());
+
+        out.push_str(&format!("{}:\n", index));
+        for row in shape.chunks(3) {
+            let row: String = row.iter().map(|&c| if c { '#' } else { '.' }).collect();
+            out.push_str(&row);
+            out.push('\n');
+        }
+        out.push('\n');
+    }
+
+    for _ in 0..size {
+        let (width, height) = (rng.random_range(3..30), rng.random_range(3..30));
+        let mut counts = [0; SHAPES];
+        if rng.random_bool(0.5) {
+            for _ in 0..rng.random_range(0..=(width / 3) * (height / 3)) {
+                counts[rng.random_range(0..SHAPES)] += 1;
+            }
+        } else {
+            let mut filled = 0;
+            while filled <= width * height {
+                let shape = rng.random_range(0..SHAPES);
+                counts[shape] += 1;
+                filled += cells[shape];
+            }
+        }
+
+        let counts: Vec<String> = counts.iter().map(usize::to_string).collect();
+        out.push_str(&format!("{}x{}: {}\n", width, height, counts.join(" ")));
+    }
+
+    out
+}
@@ -0,0 +1,223 @@
+use aoc_core::{
+    Line, ParseError, Solution, lines,
+    trace::{self, Event, Verbosity},
+};
+
+#[cfg(feature = "generate")]
+pub mod generate;
+
+#[derive(Debug, Clone)]
+pub struct Shape {
+    pub grid: Vec<Vec<bool>>,
+    pub width: usize,
+    pub height: usize,
+}
+
+impl Shape {
+    pub fn parse(lines: &[Line]) -> Result<Self, ParseError> {
+        let mut grid: Vec<Vec<bool>> = Vec::new();
+        for line in lines {
+            if let Some((i, c)) = line
+                .text
+                .char_indices()
+                .find(|&(_, c)| c != '#' && c != '.')
+            {
+                return Err(line.error(&line.text[i..i + c.len_utf8()], "a shape cell `#` or `.`"));
+            }
+            grid.push(line.text.chars().map(|c| c == '#').collect());
+        }
+
+        let height = grid.len();
+        let width = if height > 0 { grid[0].len() } else { 0 };
+
+        Ok(Shape {
+            grid,
+            width,
+            height,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Region {
+    pub width: usize,
+    pub height: usize,
+    pub shape_counts: Vec<usize>,
+}
+
+impl Region {
+    pub fn new(width: usize, height: usize, shape_counts: Vec<usize>) -> Self {
+        Region {
+            width,
+            height,
+            shape_counts,
+        }
+    }
+
+    pub fn parse(line: Line) -> Result<Self, ParseError> {
+        // Parse region definition (e.g., "4x4: 0 0 0 0 2 0")
+        let (dimensions, counts) = line
+            .text
+            .split_once(':')
+            .ok_or_else(|| line.error(line.text, "a region `WxH: counts`"))?;
+        let dimensions = dimensions.trim();
+        let (width, height) = dimensions
+            .split_once('x')
+            .ok_or_else(|| line.error(dimensions, "region dimensions `WxH`"))?;
+
+        let shape_counts = counts
+            .split_whitespace()
+            .map(|s| line.parse(s, "a shape count"))
+            .collect::<Result<Vec<usize>, _>>()?;
+
+        Ok(Region::new(
+            line.parse(width, "a region width")?,
+            line.parse(height, "a region height")?,
+            shape_counts,
+        ))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Input {
+    pub shapes: Vec<Shape>,
+    pub regions: Vec<Region>,
+}
+
+impl Input {
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let mut shapes = Vec::new();
+        let mut regions = Vec::new();
+
+        let lines: Vec<Line> = lines(input).collect();
+        let mut i = 0;
+
+        while i < lines.len() {
+            let line = lines[i].text.trim();
+
+            if line.ends_with(':') {
+                let start = i + 1;
+                i += 1;
+
+                while i < lines.len()
+                    && !lines[i].text.trim().is_empty()
+                    && !lines[i].text.contains(':')
+                {
+                    i += 1;
+                }
+
+                shapes.push(Shape::parse(&lines[start..i])?);
+            } else if line.contains('x') && line.contains(':') {
+                regions.push((Region::parse(lines[i])?, lines[i]));
+                i += 1;
+            } else if line.is_empty() {
+                i += 1;
+            } else {
+                return Err(lines[i].error(line, "a shape header `N:` or a region `WxH: counts`"));
+            }
+        }
+
+        // Regions may only count the shapes that exist, wherever they are defined.
+        for (region, line) in &regions {
+            if region.shape_counts.len() > shapes.len() {
+                let (_, counts) = line.text.split_once(':').unwrap_or_default();
+                let extra = counts
+                    .split_whitespace()
+                    .nth(shapes.len())
+                    .unwrap_or_default();
+                return Err(line.error(
+                    extra,
+                    format!("end of line after {} shape counts", shapes.len()),
+                ));
+            }
+        }
+
+        Ok(Input {
+            shapes,
+            regions: regions.into_iter().map(|(region, _)| region).collect(),
+        })
+    }
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+
+    type Input = Input;
+    type Answer1 = usize;
+    type Answer2 = ();
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Input::parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        part1(input)
+    }
+
+    fn part2(_input: &Self::Input) -> Self::Answer2 {}
+}
+
+pub fn part1(input: &Input) -> usize {
+    let mut total = 0;
+
+    for region in &input.regions {
+        let shapes_size = region
+            .shape_counts
+            .iter()
+            .enumerate()
+            .fold(0, |acc, (idx, &count)| {
+                acc + input.shapes[idx].width * input.shapes[idx].height * count
+            });
+
+        let fits = shapes_size <= region.width * region.height;
+        trace::emit(Verbosity::Steps, || {
+            Event::new("region")
+                .with("size", format!("{}x{}", region.width, region.height))
+                .with("shape_cells", shapes_size)
+                .with("fits", fits)
+        });
+        if fits {
+            total += 1;
+        }
+    }
+
+    total
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_core::example_tests!(Day12);
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            Input::parse("0:\n###\n#o.").unwrap_err(),
+            ParseError::new(3, 2, "o", "a shape cell `#` or `.`")
+        );
+        assert_eq!(
+            Input::parse("0:\n###\n\n4x4: 0 two").unwrap_err(),
+            ParseError::new(4, 8, "two", "a shape count")
+        );
+        assert_eq!(
+            Input::parse("0:\n###\n\n4x4: 1 2").unwrap_err(),
+            ParseError::new(4, 8, "2", "end of line after 1 shape counts")
+        );
+        assert_eq!(
+            Input::parse("4xq: 1").unwrap_err(),
+            ParseError::new(1, 3, "q", "a region height")
+        );
+        assert_eq!(
+            Input::parse("0:\n###\n\nshape").unwrap_err(),
+            ParseError::new(
+                4,
+                1,
+                "shape",
+                "a shape header `N:` or a region `WxH: counts`"
+            )
+        );
+    }
+}
@@ -0,0 +1,37 @@
+use day12::{Input, generate, part1};
+
+/// Whether the presents of `region` certainly fit (one per 3x3 tile) or certainly do not
+/// (more `#` cells than room), or `None` if neither is obvious.
+fn decide(input: &Input, region: usize) -> Option<bool> {
+    let region = &input.regions[region];
+    let presents: usize = region.shape_counts.iter().sum();
+    let filled: usize = region
+        .shape_counts
+        .iter()
+        .zip(&input.shapes)
+        .map(|(count, shape)| count * shape.grid.iter().flatten().filter(|&&c| c).count())
+        .sum();
+
+    if presents <= (region.width / 3) * (region.height / 3) {
+        Some(true)
+    } else if filled > region.width * region.height {
+        Some(false)
+    } else {
+        None
+    }
+}
+
+#[test]
+fn test_part1_matches_obvious_regions() {
+    for seed in 0..1000 {
+        let input = generate::input(seed, 1 + seed as usize % 20);
+        let parsed = Input::parse(&input).unwrap();
+
+        let fitting = (0..parsed.regions.len())
+            .map(|region| decide(&parsed, region).expect("an obvious region"))
+            .filter(|&fits| fits)
+            .count();
+
+        assert_eq!(part1(&parsed), fitting, "seed {}:\n{}", seed, input);
+    }
+}